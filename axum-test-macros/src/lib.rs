@@ -0,0 +1,148 @@
+//!
+//! Macros for [axum-test](https://docs.rs/axum-test).
+//!
+//! These are re-exported from `axum_test` when the `macros` feature is enabled,
+//! and should be used from there.
+//!
+
+use ::proc_macro::TokenStream;
+use ::proc_macro2::TokenStream as TokenStream2;
+use ::quote::quote;
+use ::syn::parse::Parse;
+use ::syn::parse::ParseStream;
+use ::syn::parse_macro_input;
+use ::syn::parse_quote;
+use ::syn::Error;
+use ::syn::Expr;
+use ::syn::FnArg;
+use ::syn::Ident;
+use ::syn::ItemFn;
+use ::syn::Result;
+use ::syn::Token;
+
+///
+/// Marks an async function as a test, which is given a `TestServer` running the app provided.
+///
+/// This wraps `#[tokio::test]`, so `tokio` must be a dependency with the `macros` and `rt` features.
+///
+/// The first argument is the app to run. When it is a path, such as `app`,
+/// it is called as a function to build the app. Otherwise it is used as is,
+/// such as `Router::new()`.
+///
+/// A [`TestServerConfig`](https://docs.rs/axum-test/latest/axum_test/struct.TestServerConfig.html)
+/// can be passed with `config = ...`, and otherwise the default config is used.
+///
+/// ```rust,ignore
+/// use ::axum::routing::get;
+/// use ::axum::Router;
+/// use ::axum_test::TestServer;
+/// use ::axum_test::TestServerConfig;
+///
+/// fn app() -> Router {
+///     Router::new().route("/ping", get(|| async { "pong!" }))
+/// }
+///
+/// #[axum_test::test(app)]
+/// async fn it_should_ping(server: TestServer) {
+///     server.get("/ping").await.assert_text("pong!");
+/// }
+///
+/// #[axum_test::test(app, config = TestServerConfig::builder().expect_success_by_default().build())]
+/// #[should_panic]
+/// async fn it_should_fail_on_missing_routes(server: TestServer) {
+///     server.get("/missing").await;
+/// }
+/// ```
+///
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as TestArgs);
+    let function = parse_macro_input!(item as ItemFn);
+
+    expand_test(args, function)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The arguments given to `#[axum_test::test(...)]`.
+struct TestArgs {
+    app: Expr,
+    config: Option<Expr>,
+}
+
+impl Parse for TestArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let app = input.parse()?;
+        let mut config = None;
+
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let name: Ident = input.parse()?;
+            if name != "config" {
+                return Err(Error::new(
+                    name.span(),
+                    format!("unknown argument `{name}`, expected `config = ...`"),
+                ));
+            }
+
+            input.parse::<Token![=]>()?;
+            config = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected arguments, expected `app` or `app, config = ...`"));
+        }
+
+        Ok(Self { app, config })
+    }
+}
+
+fn expand_test(args: TestArgs, mut function: ItemFn) -> Result<TokenStream2> {
+    if function.sig.asyncness.is_none() {
+        return Err(Error::new_spanned(
+            function.sig.fn_token,
+            "the `async` keyword is missing from the test function",
+        ));
+    }
+
+    if function.sig.inputs.len() != 1 {
+        return Err(Error::new_spanned(
+            &function.sig.inputs,
+            "expected the test function to take a single `TestServer` parameter",
+        ));
+    }
+
+    let server_arg = match function.sig.inputs.pop().map(|pair| pair.into_value()) {
+        Some(FnArg::Typed(server_arg)) => server_arg,
+        _ => {
+            return Err(Error::new_spanned(
+                &function.sig,
+                "expected the test function to take a single `TestServer` parameter",
+            ))
+        }
+    };
+
+    let server_pat = &server_arg.pat;
+    let server_ty = &server_arg.ty;
+    let app = match &args.app {
+        Expr::Path(app_fn) => quote!(#app_fn()),
+        app => quote!(#app),
+    };
+    let config = match &args.config {
+        Some(config) => quote!(#config),
+        None => quote!(::axum_test::TestServerConfig::default()),
+    };
+
+    let body = &function.block;
+    function.block = parse_quote!({
+        let #server_pat: #server_ty = ::axum_test::TestServer::new_with_config(#app, #config)
+            .expect("Should create test server");
+
+        #body
+    });
+
+    Ok(quote! {
+        #[::tokio::test]
+        #function
+    })
+}
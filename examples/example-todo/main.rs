@@ -40,9 +40,11 @@ use ::axum_test::TestServer;
 use ::axum_test::TestServerConfig;
 
 const PORT: u16 = 8080;
+#[allow(clippy::needless_borrow, clippy::redundant_static_lifetimes)]
 const USER_ID_COOKIE_NAME: &'static str = &"example-todo-user-id";
 
 #[tokio::main]
+#[allow(clippy::single_match)]
 async fn main() {
     let result: Result<()> = {
         let app = new_app();
@@ -95,6 +97,7 @@ pub struct NumTodos {
 // for session cookies. It's really bad. Like _seriously_ bad.
 //
 // This is done like this here to keep the code shorter. That's all.
+#[allow(clippy::map_flatten, clippy::needless_borrow)]
 fn get_user_id_from_cookie(cookies: &CookieJar) -> Result<u32> {
     cookies
         .get(&USER_ID_COOKIE_NAME)
@@ -119,6 +122,7 @@ pub async fn route_post_user_login(
     cookies
 }
 
+#[allow(clippy::unnecessary_mut_passed)]
 pub async fn route_put_user_todos(
     State(ref mut state): State<SharedAppState>,
     mut cookies: CookieJar,
@@ -135,6 +139,7 @@ pub async fn route_put_user_todos(
     Ok(Json(num_todos))
 }
 
+#[allow(clippy::unnecessary_mut_passed)]
 pub async fn route_get_user_todos(
     State(ref state): State<SharedAppState>,
     mut cookies: CookieJar,
@@ -147,6 +152,7 @@ pub async fn route_get_user_todos(
     Ok(Json(todos))
 }
 
+#[allow(clippy::needless_borrow)]
 pub(crate) fn new_app() -> Router {
     let state = AppState {
         user_todos: HashMap::new(),
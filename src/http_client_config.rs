@@ -0,0 +1,150 @@
+use ::axum::body::Body;
+use ::hyper_util::client::legacy::connect::HttpConnector;
+use ::hyper_util::client::legacy::Builder;
+use ::hyper_util::client::legacy::Client;
+use ::hyper_util::rt::TokioExecutor;
+use ::std::fmt;
+use ::std::sync::Arc;
+
+type ConfigureClientFn = dyn Fn(&mut Builder, &mut HttpConnector) + Send + Sync;
+
+///
+/// This is for customising the HTTP client used to send requests,
+/// when the `TestServer` is running on a real port.
+///
+/// It is given the `hyper_util` client [`Builder`](::hyper_util::client::legacy::Builder),
+/// and the [`HttpConnector`](::hyper_util::client::legacy::connect::HttpConnector) used,
+/// before the client is built. Allowing settings like the pool size,
+/// or connection timeouts, to be changed.
+///
+/// This is ignored when using the mock transport.
+///
+/// ```rust
+/// use ::axum_test::HttpClientConfig;
+/// use ::axum_test::TestServerConfig;
+/// use ::axum_test::Transport;
+/// use ::std::time::Duration;
+///
+/// let config = TestServerConfig::builder()
+///     .transport(Transport::HttpRandomPort)
+///     .http_client_config(HttpClientConfig::new(|builder, connector| {
+///         builder.pool_max_idle_per_host(0);
+///         connector.set_connect_timeout(Some(Duration::from_millis(100)));
+///     }))
+///     .build();
+/// ```
+///
+#[derive(Clone)]
+pub struct HttpClientConfig {
+    configure: Arc<ConfigureClientFn>,
+}
+
+impl HttpClientConfig {
+    pub fn new<F>(configure: F) -> Self
+    where
+        F: Fn(&mut Builder, &mut HttpConnector) + Send + Sync + 'static,
+    {
+        Self {
+            configure: Arc::new(configure),
+        }
+    }
+
+    pub(crate) fn build_client(maybe_config: Option<&Self>) -> Client<HttpConnector, Body> {
+        let mut builder = Client::builder(TokioExecutor::new());
+        let mut connector = HttpConnector::new();
+
+        if let Some(config) = maybe_config {
+            (config.configure)(&mut builder, &mut connector);
+        }
+
+        builder.build(connector)
+    }
+}
+
+impl fmt::Debug for HttpClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HttpClientConfig {{ configure: {{unknown}} }}")
+    }
+}
+
+impl PartialEq for HttpClientConfig {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.configure, &other.configure)
+    }
+}
+
+#[cfg(test)]
+mod test_http_client_config {
+    use ::axum::routing::get;
+    use ::axum::Router;
+    use ::std::sync::atomic::AtomicUsize;
+    use ::std::sync::atomic::Ordering;
+    use ::std::sync::Arc;
+    use ::tokio::io::AsyncReadExt;
+    use ::tokio::io::AsyncWriteExt;
+    use ::tokio::net::TcpListener;
+    use ::tokio::spawn;
+
+    use crate::HttpClientConfig;
+    use crate::TestServer;
+    use crate::TestServerConfig;
+    use crate::Transport;
+
+    async fn get_ping() -> &'static str {
+        "pong!"
+    }
+
+    #[tokio::test]
+    async fn it_should_configure_client_used_for_requests() {
+        let num_configured = Arc::new(AtomicUsize::new(0));
+        let num_configured_clone = num_configured.clone();
+
+        let app = Router::new().route("/ping", get(get_ping));
+        let config = TestServerConfig::builder()
+            .transport(Transport::HttpRandomPort)
+            .http_client_config(HttpClientConfig::new(move |builder, _| {
+                builder.pool_max_idle_per_host(0);
+                num_configured_clone.fetch_add(1, Ordering::SeqCst);
+            }))
+            .build();
+        let server = TestServer::new_with_config(app, config).expect("Should create test server");
+
+        server.get("/ping").await.assert_text("pong!");
+        assert_eq!(num_configured.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_apply_client_settings_to_requests_sent() {
+        // A raw listener, so we can see the request exactly as it was written.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let listener_url = format!("http://{}/ping", listener.local_addr().unwrap());
+        let raw_request = spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0; 1024];
+            let num_read = stream.read(&mut buffer).await.unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+
+            String::from_utf8_lossy(&buffer[..num_read]).to_string()
+        });
+
+        let app = Router::new().route("/ping", get(get_ping));
+        let config = TestServerConfig::builder()
+            .transport(Transport::HttpRandomPort)
+            .http_client_config(HttpClientConfig::new(|builder, _| {
+                builder.http1_title_case_headers(true);
+            }))
+            .build();
+        let server = TestServer::new_with_config(app, config).expect("Should create test server");
+
+        server.get(&listener_url).await.assert_status_ok();
+
+        let raw_request = raw_request.await.unwrap();
+        assert!(
+            raw_request.contains("\r\nHost: "),
+            "Expected title case headers, received: {raw_request}"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+/// Parses the value of a `Cache-Control` header into it's directives.
+///
+/// Each directive is returned as it's lowercase name,
+/// and it's value if it has one (with any surrounding quotes removed).
+pub fn parse_cache_control(header_value: &str) -> Vec<(String, Option<String>)> {
+    header_value
+        .split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(|directive| match directive.split_once('=') {
+            Some((name, value)) => (
+                name.trim().to_lowercase(),
+                Some(value.trim().trim_matches('"').to_string()),
+            ),
+            None => (directive.to_lowercase(), None),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_parse_cache_control {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_directives_with_and_without_values() {
+        let directives = parse_cache_control("Private, max-age=\"60\",  no-store");
+
+        assert_eq!(
+            directives,
+            vec![
+                ("private".to_string(), None),
+                ("max-age".to_string(), Some("60".to_string())),
+                ("no-store".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_nothing_for_empty_header() {
+        assert_eq!(parse_cache_control(""), vec![]);
+    }
+}
@@ -0,0 +1,80 @@
+use ::anyhow::anyhow;
+use ::anyhow::Result;
+use ::axum::body::Body;
+use ::axum::body::HttpBody;
+use ::axum::BoxError;
+use ::bytes::Bytes;
+use ::http::response::Parts;
+use ::http::HeaderMap;
+use ::http::Request;
+use ::http::Response;
+use ::http_body_util::BodyExt;
+use ::http_body_util::LengthLimitError;
+use ::http_body_util::Limited;
+use ::std::error::Error;
+use ::std::fmt;
+
+/// The max size of the response body, for the transport to stop reading at.
+///
+/// This is stored in the request extensions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxResponseBodySize(pub usize);
+
+/// The trailers sent after the response body.
+///
+/// This is stored in the response extensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseTrailers(pub HeaderMap);
+
+/// Returned when collecting a response body larger than the `MaxResponseBodySize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseBodyTooLargeError;
+
+impl fmt::Display for ResponseBodyTooLargeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Response body exceeds the max response body size")
+    }
+}
+
+impl Error for ResponseBodyTooLargeError {}
+
+/// Returns the max response body size set on the request, if there is one.
+pub fn max_response_body_size<B>(request: &Request<B>) -> Option<usize> {
+    request
+        .extensions()
+        .get::<MaxResponseBodySize>()
+        .map(|max_size| max_size.0)
+}
+
+/// Reads the whole of the response body, up to the max size given.
+///
+/// Any trailers sent are stored in the extensions of the parts returned.
+pub async fn collect_response<B>(
+    response: Response<B>,
+    max_body_size: Option<usize>,
+) -> Result<(Parts, Bytes)>
+where
+    B: HttpBody<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    let (mut parts, response_body) = response.into_parts();
+    let collected_body = match max_body_size {
+        None => Body::new(response_body).collect().await?,
+        Some(max_body_size) => Limited::new(response_body, max_body_size)
+            .collect()
+            .await
+            .map_err(|err| {
+                if err.is::<LengthLimitError>() {
+                    anyhow!(ResponseBodyTooLargeError)
+                } else {
+                    anyhow!(err)
+                }
+            })?,
+    };
+
+    if let Some(trailers) = collected_body.trailers() {
+        parts.extensions.insert(ResponseTrailers(trailers.clone()));
+    }
+
+    Ok((parts, collected_body.to_bytes()))
+}
@@ -0,0 +1,72 @@
+use ::cookie::time::Duration;
+use ::cookie::time::OffsetDateTime;
+use ::cookie::Cookie;
+use ::cookie::CookieJar;
+
+/// Returns true if the cookie given, when returned by a `Set-Cookie` header,
+/// is asking for the cookie to be removed.
+///
+/// This is when it has a `Max-Age` of zero (or less),
+/// or an `Expires` date in the past.
+pub fn is_removal_cookie(cookie: &Cookie) -> bool {
+    if let Some(max_age) = cookie.max_age() {
+        return max_age <= Duration::ZERO;
+    }
+
+    if let Some(expires) = cookie.expires_datetime() {
+        return expires <= OffsetDateTime::now_utc();
+    }
+
+    false
+}
+
+/// Applies a cookie returned from a `Set-Cookie` header onto the jar.
+///
+/// Removal cookies will remove the cookie with the same name from the jar,
+/// and all other cookies are stored over the top of any existing cookie.
+pub fn merge_cookie_into_jar(jar: &mut CookieJar, cookie: Cookie<'static>) {
+    if is_removal_cookie(&cookie) {
+        jar.remove(Cookie::from(cookie.name().to_string()));
+    } else {
+        jar.add(cookie);
+    }
+}
+
+#[cfg(test)]
+mod test_is_removal_cookie {
+    use super::*;
+
+    #[test]
+    fn it_should_be_false_for_plain_cookie() {
+        let cookie = Cookie::new("my-cookie", "value");
+
+        assert!(!is_removal_cookie(&cookie));
+    }
+
+    #[test]
+    fn it_should_be_true_for_zero_max_age() {
+        let cookie = Cookie::build(("my-cookie", ""))
+            .max_age(Duration::ZERO)
+            .build();
+
+        assert!(is_removal_cookie(&cookie));
+    }
+
+    #[test]
+    fn it_should_be_true_for_expires_in_the_past() {
+        let cookie = Cookie::build(("my-cookie", ""))
+            .expires(OffsetDateTime::now_utc() - Duration::days(365))
+            .build();
+
+        assert!(is_removal_cookie(&cookie));
+    }
+
+    #[test]
+    fn it_should_be_false_for_expires_in_the_future() {
+        let cookie = Cookie::build(("my-cookie", "value"))
+            .expires(OffsetDateTime::now_utc() + Duration::days(365))
+            .build();
+
+        assert!(!is_removal_cookie(&cookie));
+    }
+}
@@ -0,0 +1,36 @@
+use ::std::any::Any;
+use ::std::error::Error;
+use ::std::fmt;
+
+/// Returned by the mock transport when a handler panics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerPanicError {
+    message: String,
+}
+
+impl HandlerPanicError {
+    pub fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+
+        Self { message }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for HandlerPanicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = &self.message;
+        write!(f, "Handler panicked, with '{message}'")
+    }
+}
+
+impl Error for HandlerPanicError {}
@@ -0,0 +1,155 @@
+use ::serde::de::MapAccess;
+use ::serde::de::Visitor;
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde_json::value::RawValue;
+use ::serde_json::Result;
+use ::std::fmt;
+
+/// A Json value, compared by what is written in the Json text.
+///
+/// Unlike [`serde_json::Value`], numbers keep all of their digits,
+/// and objects keep any keys which are duplicated.
+/// Object keys are sorted, so the order they are written in is ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CanonicalJson {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<CanonicalJson>),
+    Object(Vec<(String, CanonicalJson)>),
+}
+
+/// Parses the Json given into its canonical form.
+pub fn canonical_json(json: &[u8]) -> Result<CanonicalJson> {
+    let raw_value = serde_json::from_slice::<&RawValue>(json)?;
+    canonical_raw_json(raw_value)
+}
+
+fn canonical_raw_json(raw_value: &RawValue) -> Result<CanonicalJson> {
+    let raw_json = raw_value.get();
+
+    match raw_json.as_bytes().first() {
+        Some(b'{') => {
+            let RawEntries(mut entries) = serde_json::from_str(raw_json)?;
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let canonical_entries = entries
+                .into_iter()
+                .map(|(key, value)| Ok((key, canonical_raw_json(value)?)))
+                .collect::<Result<_>>()?;
+
+            Ok(CanonicalJson::Object(canonical_entries))
+        }
+        Some(b'[') => {
+            let items: Vec<&RawValue> = serde_json::from_str(raw_json)?;
+            let canonical_items = items
+                .into_iter()
+                .map(canonical_raw_json)
+                .collect::<Result<_>>()?;
+
+            Ok(CanonicalJson::Array(canonical_items))
+        }
+        Some(b'"') => Ok(CanonicalJson::String(serde_json::from_str(raw_json)?)),
+        Some(b't') | Some(b'f') => Ok(CanonicalJson::Bool(serde_json::from_str(raw_json)?)),
+        Some(b'n') => Ok(CanonicalJson::Null),
+        _ => Ok(CanonicalJson::Number(canonical_number(raw_json))),
+    }
+}
+
+/// Rewrites the number as its significant digits and an exponent,
+/// so `100`, `100.0`, and `1e2` are all written as `1e2`.
+fn canonical_number(raw_number: &str) -> String {
+    let (sign, unsigned_number) = match raw_number.strip_prefix('-') {
+        Some(unsigned_number) => ("-", unsigned_number),
+        None => ("", raw_number),
+    };
+    let (mantissa, exponent) = unsigned_number
+        .split_once(['e', 'E'])
+        .unwrap_or((unsigned_number, "0"));
+    let (integer_digits, fraction_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = format!("{integer_digits}{fraction_digits}");
+    let significant_digits = digits.trim_start_matches('0').trim_end_matches('0');
+    if significant_digits.is_empty() {
+        return "0".to_string();
+    }
+
+    let trailing_zeros = digits.len() - digits.trim_end_matches('0').len();
+    let exponent = exponent.parse::<i64>().unwrap_or_default() - fraction_digits.len() as i64
+        + trailing_zeros as i64;
+
+    format!("{sign}{significant_digits}e{exponent}")
+}
+
+/// The entries of a Json object, in the order written, including duplicate keys.
+struct RawEntries<'a>(Vec<(String, &'a RawValue)>);
+
+impl<'de> Deserialize<'de> for RawEntries<'de> {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RawEntriesVisitor)
+    }
+}
+
+struct RawEntriesVisitor;
+
+impl<'de> Visitor<'de> for RawEntriesVisitor {
+    type Value = RawEntries<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Json object")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> ::std::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(RawEntries(entries))
+    }
+}
+
+#[cfg(test)]
+mod test_canonical_json {
+    use super::*;
+
+    #[test]
+    fn it_should_ignore_whitespace_key_order_and_escapes() {
+        let a = canonical_json(br#"{ "b": 1, "a": "\u0041" }"#).unwrap();
+        let b = canonical_json(br#"{"a":"A","b":1}"#).unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn it_should_write_equal_numbers_the_same() {
+        let a = canonical_json(br#"[100, 0.10, -0, 1.5e3]"#).unwrap();
+        let b = canonical_json(br#"[1e2, 0.1, 0, 1500.0]"#).unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn it_should_keep_all_digits_of_numbers() {
+        let a = canonical_json(br#"12345678901234567890123"#).unwrap();
+        let b = canonical_json(br#"1.2345678901234568e22"#).unwrap();
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn it_should_keep_duplicate_keys() {
+        let a = canonical_json(br#"{ "a": 1, "a": 2 }"#).unwrap();
+        let b = canonical_json(br#"{ "a": 2 }"#).unwrap();
+
+        assert_ne!(a, b);
+    }
+}
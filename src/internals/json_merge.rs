@@ -0,0 +1,57 @@
+use ::serde_json::Value;
+
+/// Deep merges the patch given into the target.
+///
+/// Objects are merged key by key, recursively.
+/// All other values in the patch, including arrays, replace what is in the target.
+pub fn merge_json(target: &mut Value, patch: &Value) {
+    match (target, patch) {
+        (Value::Object(target_map), Value::Object(patch_map)) => {
+            for (key, patch_value) in patch_map {
+                match target_map.get_mut(key) {
+                    Some(target_value) => merge_json(target_value, patch_value),
+                    None => {
+                        target_map.insert(key.clone(), patch_value.clone());
+                    }
+                }
+            }
+        }
+        (target, patch) => *target = patch.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test_merge_json {
+    use super::*;
+    use ::serde_json::json;
+
+    #[test]
+    fn it_should_merge_nested_objects() {
+        let mut target = json!({ "name": "Joe", "address": { "city": "London" } });
+        merge_json(
+            &mut target,
+            &json!({ "age": 20, "address": { "street": "Baker" } }),
+        );
+
+        assert_eq!(
+            target,
+            json!({ "name": "Joe", "age": 20, "address": { "city": "London", "street": "Baker" } })
+        );
+    }
+
+    #[test]
+    fn it_should_replace_arrays() {
+        let mut target = json!({ "pets": ["Rex", "Spot"] });
+        merge_json(&mut target, &json!({ "pets": ["Fido"] }));
+
+        assert_eq!(target, json!({ "pets": ["Fido"] }));
+    }
+
+    #[test]
+    fn it_should_replace_values_of_a_different_kind() {
+        let mut target = json!({ "address": "London" });
+        merge_json(&mut target, &json!({ "address": { "city": "Paris" } }));
+
+        assert_eq!(target, json!({ "address": { "city": "Paris" } }));
+    }
+}
@@ -0,0 +1,317 @@
+use ::serde_json::Number;
+use ::serde_json::Value;
+use ::std::fmt;
+
+/// Describes the first difference found when comparing two Json values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonMismatch {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for JsonMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = &self.path;
+        let message = &self.message;
+
+        if path.is_empty() {
+            write!(f, "at the root, {message}")
+        } else {
+            write!(f, "at '{path}', {message}")
+        }
+    }
+}
+
+/// Compares the two Json values, returning the first difference found.
+///
+/// Objects are compared ignoring key order, and arrays are compared in order.
+/// Numbers are compared using the `is_number_equal` function given,
+/// and all other values must be exactly equal.
+pub fn find_json_mismatch<F>(
+    expected: &Value,
+    received: &Value,
+    is_number_equal: F,
+) -> Option<JsonMismatch>
+where
+    F: Fn(&Number, &Number) -> bool,
+{
+    find_json_mismatch_at(String::new(), expected, received, &is_number_equal)
+}
+
+fn find_json_mismatch_at<F>(
+    path: String,
+    expected: &Value,
+    received: &Value,
+    is_number_equal: &F,
+) -> Option<JsonMismatch>
+where
+    F: Fn(&Number, &Number) -> bool,
+{
+    match (expected, received) {
+        (Value::Number(expected_number), Value::Number(received_number)) => {
+            if is_number_equal(expected_number, received_number) {
+                None
+            } else {
+                Some(JsonMismatch {
+                    path,
+                    message: format!("expected {expected_number}, received {received_number}"),
+                })
+            }
+        }
+        (Value::Array(expected_items), Value::Array(received_items)) => {
+            if expected_items.len() != received_items.len() {
+                let expected_len = expected_items.len();
+                let received_len = received_items.len();
+
+                return Some(JsonMismatch {
+                    path,
+                    message: format!(
+                        "expected array of length {expected_len}, received length {received_len}"
+                    ),
+                });
+            }
+
+            expected_items
+                .iter()
+                .zip(received_items)
+                .enumerate()
+                .find_map(|(index, (expected_item, received_item))| {
+                    let item_path = format!("{path}[{index}]");
+                    find_json_mismatch_at(item_path, expected_item, received_item, is_number_equal)
+                })
+        }
+        (Value::Object(expected_map), Value::Object(received_map)) => {
+            for (key, expected_value) in expected_map {
+                let key_path = join_json_path(&path, key);
+
+                match received_map.get(key) {
+                    None => {
+                        return Some(JsonMismatch {
+                            path: key_path,
+                            message: "key is missing".to_string(),
+                        })
+                    }
+                    Some(received_value) => {
+                        let maybe_mismatch = find_json_mismatch_at(
+                            key_path,
+                            expected_value,
+                            received_value,
+                            is_number_equal,
+                        );
+
+                        if maybe_mismatch.is_some() {
+                            return maybe_mismatch;
+                        }
+                    }
+                }
+            }
+
+            received_map
+                .keys()
+                .find(|key| !expected_map.contains_key(*key))
+                .map(|key| JsonMismatch {
+                    path: join_json_path(&path, key),
+                    message: "key was not expected".to_string(),
+                })
+        }
+        _ => {
+            if expected == received {
+                None
+            } else {
+                Some(JsonMismatch {
+                    path,
+                    message: format!("expected {expected}, received {received}"),
+                })
+            }
+        }
+    }
+}
+
+/// Finds everything in the received value which is not in the superset given.
+///
+/// Every key in a received object must also be in the superset, with a matching value,
+/// although the superset may contain extra keys. Arrays are compared in order,
+/// and all other values must be exactly equal.
+pub fn find_json_subset_mismatches(received: &Value, superset: &Value) -> Vec<JsonMismatch> {
+    let mut mismatches = Vec::new();
+    find_json_subset_mismatches_at(String::new(), received, superset, &mut mismatches);
+    mismatches
+}
+
+fn find_json_subset_mismatches_at(
+    path: String,
+    received: &Value,
+    superset: &Value,
+    mismatches: &mut Vec<JsonMismatch>,
+) {
+    match (received, superset) {
+        (Value::Object(received_map), Value::Object(superset_map)) => {
+            for (key, received_value) in received_map {
+                let key_path = join_json_path(&path, key);
+
+                match superset_map.get(key) {
+                    None => mismatches.push(JsonMismatch {
+                        path: key_path,
+                        message: "key was not expected".to_string(),
+                    }),
+                    Some(superset_value) => find_json_subset_mismatches_at(
+                        key_path,
+                        received_value,
+                        superset_value,
+                        mismatches,
+                    ),
+                }
+            }
+        }
+        (Value::Array(received_items), Value::Array(superset_items)) => {
+            for (index, received_item) in received_items.iter().enumerate() {
+                let item_path = format!("{path}[{index}]");
+
+                match superset_items.get(index) {
+                    None => mismatches.push(JsonMismatch {
+                        path: item_path,
+                        message: "item was not expected".to_string(),
+                    }),
+                    Some(superset_item) => find_json_subset_mismatches_at(
+                        item_path,
+                        received_item,
+                        superset_item,
+                        mismatches,
+                    ),
+                }
+            }
+        }
+        _ => {
+            if received != superset {
+                mismatches.push(JsonMismatch {
+                    path,
+                    message: format!("expected {superset}, received {received}"),
+                });
+            }
+        }
+    }
+}
+
+pub fn join_json_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod test_find_json_mismatch {
+    use super::*;
+    use ::serde_json::json;
+
+    fn is_exactly_equal(expected: &Number, received: &Number) -> bool {
+        expected == received
+    }
+
+    #[test]
+    fn it_should_return_none_for_matching_values() {
+        let expected = json!({ "name": "Joe", "pets": [{ "name": "Rex" }] });
+        let received = json!({ "pets": [{ "name": "Rex" }], "name": "Joe" });
+
+        let mismatch = find_json_mismatch(&expected, &received, is_exactly_equal);
+
+        assert_eq!(mismatch, None);
+    }
+
+    #[test]
+    fn it_should_return_path_to_nested_difference() {
+        let expected = json!({ "pets": [{ "name": "Rex" }, { "name": "Spot" }] });
+        let received = json!({ "pets": [{ "name": "Rex" }, { "name": "Fido" }] });
+
+        let mismatch = find_json_mismatch(&expected, &received, is_exactly_equal).unwrap();
+
+        assert_eq!(mismatch.path, "pets[1].name");
+        assert_eq!(
+            format!("{mismatch}"),
+            r#"at 'pets[1].name', expected "Spot", received "Fido""#
+        );
+    }
+
+    #[test]
+    fn it_should_report_missing_and_unexpected_keys() {
+        let missing = find_json_mismatch(
+            &json!({ "name": "Joe", "age": 20 }),
+            &json!({ "name": "Joe" }),
+            is_exactly_equal,
+        )
+        .unwrap();
+        assert_eq!(format!("{missing}"), "at 'age', key is missing");
+
+        let unexpected = find_json_mismatch(
+            &json!({ "name": "Joe" }),
+            &json!({ "name": "Joe", "age": 20 }),
+            is_exactly_equal,
+        )
+        .unwrap();
+        assert_eq!(format!("{unexpected}"), "at 'age', key was not expected");
+    }
+
+    #[test]
+    fn it_should_report_differences_at_the_root() {
+        let mismatch = find_json_mismatch(&json!([1, 2]), &json!([1]), is_exactly_equal).unwrap();
+
+        assert_eq!(
+            format!("{mismatch}"),
+            "at the root, expected array of length 2, received length 1"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_find_json_subset_mismatches {
+    use super::*;
+    use ::serde_json::json;
+
+    #[test]
+    fn it_should_return_nothing_for_a_subset() {
+        let received = json!({ "name": "Joe", "pets": [{ "name": "Rex" }] });
+        let superset = json!({ "name": "Joe", "age": 20, "pets": [{ "name": "Rex", "age": 3 }] });
+
+        assert_eq!(find_json_subset_mismatches(&received, &superset), vec![]);
+    }
+
+    #[test]
+    fn it_should_return_every_unexpected_key() {
+        let received =
+            json!({ "name": "Joe", "password": "hunter2", "pets": [{ "token": "abc" }] });
+        let superset = json!({ "name": "Joe", "pets": [{ "name": "Rex" }] });
+
+        let mismatches: Vec<String> = find_json_subset_mismatches(&received, &superset)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            mismatches,
+            vec![
+                "at 'password', key was not expected",
+                "at 'pets[0].token', key was not expected",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_differing_values() {
+        let received = json!({ "name": "Julia", "pets": [1, 2] });
+        let superset = json!({ "name": "Joe", "pets": [1] });
+
+        let mismatches: Vec<String> = find_json_subset_mismatches(&received, &superset)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            mismatches,
+            vec![
+                r#"at 'name', expected "Joe", received "Julia""#,
+                "at 'pets[1]', item was not expected",
+            ]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use ::serde_json::Value;
+
+/// A part of a Json path, either a key into an object or an index into an array.
+enum JsonPathPart<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Parses a path such as `user.id` or `pets[0].name`, returning `None` if it is malformed.
+///
+/// This is the same format used for paths in Json mismatch messages,
+/// so a path can be copied from a failure into an assertion.
+fn parse_json_path(path: &str) -> Option<Vec<JsonPathPart<'_>>> {
+    let mut parts = Vec::new();
+
+    for segment in path.split('.') {
+        let (key, mut indexes) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+        if !key.is_empty() || indexes.is_empty() {
+            parts.push(JsonPathPart::Key(key));
+        }
+
+        while let Some(rest) = indexes.strip_prefix('[') {
+            let (index, after) = rest.split_once(']')?;
+            parts.push(JsonPathPart::Index(index.parse().ok()?));
+            indexes = after;
+        }
+
+        if !indexes.is_empty() {
+            return None;
+        }
+    }
+
+    Some(parts)
+}
+
+/// Returns the value at the path given, such as `user.id` or `pets[0].name`.
+pub fn get_json_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    parse_json_path(path)?
+        .into_iter()
+        .try_fold(value, |value, part| match (value, part) {
+            (Value::Object(map), JsonPathPart::Key(key)) => map.get(key),
+            (Value::Array(items), JsonPathPart::Index(index)) => items.get(index),
+            _ => None,
+        })
+}
+
+/// Removes the value at the path given, such as `user.id` or `pets[0].name`.
+///
+/// If nothing exists at that path, then the value is left unchanged.
+pub fn remove_json_path(value: &mut Value, path: &str) {
+    let Some(mut parts) = parse_json_path(path) else {
+        return;
+    };
+    let Some(last_part) = parts.pop() else {
+        return;
+    };
+
+    let maybe_parent = parts
+        .into_iter()
+        .try_fold(value, |value, part| match (value, part) {
+            (Value::Object(map), JsonPathPart::Key(key)) => map.get_mut(key),
+            (Value::Array(items), JsonPathPart::Index(index)) => items.get_mut(index),
+            _ => None,
+        });
+
+    match (maybe_parent, last_part) {
+        (Some(Value::Object(map)), JsonPathPart::Key(key)) => {
+            map.remove(key);
+        }
+        (Some(Value::Array(items)), JsonPathPart::Index(index)) if index < items.len() => {
+            items.remove(index);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test_get_json_path {
+    use super::*;
+    use ::serde_json::json;
+
+    #[test]
+    fn it_should_get_nested_values_through_arrays() {
+        let value = json!({ "pets": [{ "name": "Rex" }, { "name": "Spot" }] });
+
+        assert_eq!(get_json_path(&value, "pets[1].name"), Some(&json!("Spot")));
+    }
+
+    #[test]
+    fn it_should_return_none_for_paths_which_do_not_exist() {
+        let value = json!({ "pets": [{ "name": "Rex" }] });
+
+        assert_eq!(get_json_path(&value, "pets[1].name"), None);
+        assert_eq!(get_json_path(&value, "pets[0].name.first"), None);
+        assert_eq!(get_json_path(&value, "owner"), None);
+    }
+
+    #[test]
+    fn it_should_get_index_at_the_root() {
+        let value = json!([{ "name": "Rex" }, { "name": "Spot" }]);
+
+        assert_eq!(get_json_path(&value, "[1].name"), Some(&json!("Spot")));
+    }
+
+    #[test]
+    fn it_should_get_nested_array_indexes() {
+        let value = json!({ "grid": [[1, 2], [3, 4]] });
+
+        assert_eq!(get_json_path(&value, "grid[1][0]"), Some(&json!(3)));
+    }
+
+    #[test]
+    fn it_should_not_use_dotted_numbers_as_indexes() {
+        let value = json!({ "pets": [{ "name": "Rex" }] });
+
+        assert_eq!(get_json_path(&value, "pets.0.name"), None);
+    }
+
+    #[test]
+    fn it_should_return_none_for_malformed_paths() {
+        let value = json!({ "pets": [{ "name": "Rex" }] });
+
+        assert_eq!(get_json_path(&value, "pets[0"), None);
+        assert_eq!(get_json_path(&value, "pets[first]"), None);
+        assert_eq!(get_json_path(&value, "pets[0]name"), None);
+    }
+}
+
+#[cfg(test)]
+mod test_remove_json_path {
+    use super::*;
+    use ::serde_json::json;
+
+    #[test]
+    fn it_should_remove_top_level_key() {
+        let mut value = json!({ "id": 123, "name": "Joe" });
+
+        remove_json_path(&mut value, "id");
+
+        assert_eq!(value, json!({ "name": "Joe" }));
+    }
+
+    #[test]
+    fn it_should_remove_nested_keys_through_arrays() {
+        let mut value =
+            json!({ "pets": [{ "id": 1, "name": "Rex" }, { "id": 2, "name": "Spot" }] });
+
+        remove_json_path(&mut value, "pets[1].id");
+
+        assert_eq!(
+            value,
+            json!({ "pets": [{ "id": 1, "name": "Rex" }, { "name": "Spot" }] })
+        );
+    }
+
+    #[test]
+    fn it_should_ignore_paths_which_do_not_exist() {
+        let mut value = json!({ "name": "Joe" });
+
+        remove_json_path(&mut value, "user.id");
+        remove_json_path(&mut value, "name.first");
+
+        assert_eq!(value, json!({ "name": "Joe" }));
+    }
+}
@@ -18,3 +18,39 @@ pub use self::starting_tcp_setup::*;
 
 mod with_this_mut;
 pub use self::with_this_mut::*;
+
+mod cookie_jar_merge;
+pub use self::cookie_jar_merge::*;
+
+mod json_mismatch;
+pub use self::json_mismatch::*;
+
+mod json_path;
+pub use self::json_path::*;
+
+mod json_merge;
+pub use self::json_merge::*;
+
+mod json_canonical;
+pub use self::json_canonical::*;
+
+mod handler_panic_error;
+pub use self::handler_panic_error::*;
+
+mod collect_response;
+pub use self::collect_response::*;
+
+mod cache_control;
+pub use self::cache_control::*;
+
+mod request_id;
+pub use self::request_id::*;
+
+mod base64;
+pub use self::base64::*;
+
+mod path_segment;
+pub use self::path_segment::*;
+
+mod named_routes;
+pub use self::named_routes::*;
@@ -0,0 +1,155 @@
+use ::anyhow::anyhow;
+use ::anyhow::Result;
+use ::std::collections::HashMap;
+use ::std::fmt::Display;
+
+use crate::internals::encode_path_segment;
+
+/// Paths registered by name, for building request paths from the route name.
+///
+/// Paths use the same syntax as Axum routes, such as `/users/:id` and `/files/*path`.
+#[derive(Debug, Clone, Default)]
+pub struct NamedRoutes {
+    routes: HashMap<String, String>,
+}
+
+impl NamedRoutes {
+    pub fn new<I>(routes: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Self {
+            routes: routes.into_iter().collect(),
+        }
+    }
+
+    /// Builds the path for the named route, replacing each of it's params.
+    ///
+    /// Params are percent-encoded. Wildcard params may contain slashes,
+    /// which are kept as separators between the encoded segments.
+    ///
+    /// This errors if the route is unknown, a param is missing,
+    /// or a param is given which the route does not have.
+    pub fn build_path<K, V>(&self, name: &str, params: &[(K, V)]) -> Result<String>
+    where
+        K: AsRef<str>,
+        V: Display,
+    {
+        let path = self
+            .routes
+            .get(name)
+            .ok_or_else(|| anyhow!("No route named '{name}' has been registered"))?;
+
+        let find_param = |param_name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key.as_ref() == param_name)
+                .map(|(_, value)| value.to_string())
+                .ok_or_else(|| {
+                    anyhow!("Missing param '{param_name}' for route '{name}' at '{path}'")
+                })
+        };
+
+        let mut used_params = Vec::new();
+        let mut segments = Vec::new();
+        for segment in path.split('/') {
+            if let Some(param_name) = segment.strip_prefix(':') {
+                let value = find_param(param_name)?;
+                segments.push(encode_path_segment(&value));
+                used_params.push(param_name);
+            } else if let Some(param_name) = segment.strip_prefix('*') {
+                let value = find_param(param_name)?;
+                let encoded: Vec<String> = value.split('/').map(encode_path_segment).collect();
+                segments.push(encoded.join("/"));
+                used_params.push(param_name);
+            } else {
+                segments.push(segment.to_string());
+            }
+        }
+
+        if let Some((unknown_param, _)) = params
+            .iter()
+            .find(|(key, _)| !used_params.contains(&key.as_ref()))
+        {
+            let unknown_param = unknown_param.as_ref();
+            return Err(anyhow!(
+                "Unknown param '{unknown_param}' for route '{name}' at '{path}'"
+            ));
+        }
+
+        Ok(segments.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod test_build_path {
+    use super::*;
+
+    fn new_named_routes() -> NamedRoutes {
+        NamedRoutes::new([
+            ("users".to_string(), "/users".to_string()),
+            (
+                "user_post".to_string(),
+                "/users/:user_id/posts/:post_id".to_string(),
+            ),
+            ("files".to_string(), "/files/*path".to_string()),
+        ])
+    }
+
+    #[test]
+    fn it_should_build_path_without_params() {
+        let path = new_named_routes()
+            .build_path::<&str, &str>("users", &[])
+            .unwrap();
+
+        assert_eq!(path, "/users");
+    }
+
+    #[test]
+    fn it_should_replace_params_with_encoded_values() {
+        let path = new_named_routes()
+            .build_path("user_post", &[("post_id", "a b"), ("user_id", "123")])
+            .unwrap();
+
+        assert_eq!(path, "/users/123/posts/a%20b");
+    }
+
+    #[test]
+    fn it_should_keep_slashes_in_wildcard_params() {
+        let path = new_named_routes()
+            .build_path("files", &[("path", "docs/my file.txt")])
+            .unwrap();
+
+        assert_eq!(path, "/files/docs/my%20file.txt");
+    }
+
+    #[test]
+    fn it_should_error_for_unknown_route() {
+        let result = new_named_routes().build_path::<&str, &str>("missing", &[]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "No route named 'missing' has been registered"
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_missing_params() {
+        let result = new_named_routes().build_path("user_post", &[("user_id", 123)]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Missing param 'post_id' for route 'user_post' at '/users/:user_id/posts/:post_id'"
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_unknown_params() {
+        let result = new_named_routes().build_path("users", &[("page", 2)]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Unknown param 'page' for route 'users' at '/users'"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use ::percent_encoding::utf8_percent_encode;
+use ::percent_encoding::AsciiSet;
+use ::percent_encoding::NON_ALPHANUMERIC;
+use ::std::fmt::Display;
+
+/// Everything except the unreserved characters from RFC 3986.
+const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encodes the value given, so it can be used as a single path segment.
+pub fn encode_path_segment<T>(value: &T) -> String
+where
+    T: Display + ?Sized,
+{
+    utf8_percent_encode(&value.to_string(), PATH_SEGMENT_ENCODE_SET).to_string()
+}
+
+/// Returns true if the format string given captures a variable inline,
+/// such as `{id}`, rather than taking it as an argument.
+///
+/// This is a `const fn` so the `test_path!` macro can reject them at compile time.
+pub const fn has_inline_path_capture(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'{' {
+            if index + 1 < bytes.len() && bytes[index + 1] == b'{' {
+                index += 2;
+                continue;
+            }
+
+            if index + 1 < bytes.len()
+                && (bytes[index + 1].is_ascii_alphabetic() || bytes[index + 1] == b'_')
+            {
+                return true;
+            }
+        }
+
+        index += 1;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod test_encode_path_segment {
+    use super::*;
+
+    #[test]
+    fn it_should_encode_reserved_characters() {
+        assert_eq!(encode_path_segment("a/b?c=d"), "a%2Fb%3Fc%3Dd");
+    }
+
+    #[test]
+    fn it_should_leave_unreserved_characters_intact() {
+        assert_eq!(
+            encode_path_segment("my-file_1.0~draft"),
+            "my-file_1.0~draft"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_has_inline_path_capture {
+    use super::*;
+
+    #[test]
+    fn it_should_find_inline_captures() {
+        assert!(has_inline_path_capture("/users/{id}"));
+        assert!(has_inline_path_capture("/users/{}/posts/{_post}"));
+    }
+
+    #[test]
+    fn it_should_allow_positional_arguments_and_escaped_braces() {
+        assert!(!has_inline_path_capture("/users/{}/posts/{0}"));
+        assert!(!has_inline_path_capture("/users/{:?}"));
+        assert!(!has_inline_path_capture("/templates/{{id}}"));
+        assert!(!has_inline_path_capture("/users"));
+    }
+}
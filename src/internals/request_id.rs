@@ -0,0 +1,64 @@
+use ::std::collections::hash_map::RandomState;
+use ::std::hash::BuildHasher;
+use ::std::hash::Hasher;
+use ::std::sync::atomic::AtomicU64;
+use ::std::sync::atomic::Ordering;
+
+static REQUEST_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Generates a random version 4 UUID, formatted in lowercase hex,
+/// for use as a request id.
+///
+/// The random bits are taken from the randomly seeded hasher in std,
+/// mixed with a counter so each call is unique within the process.
+pub fn new_request_id() -> String {
+    let counter = REQUEST_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let high = random_u64(counter);
+    let low = random_u64(!counter);
+
+    let bits = (u128::from(high) << 64) | u128::from(low);
+    let bits = (bits & !(0xf << 76)) | (0x4 << 76);
+    let bits = (bits & !(0x3 << 62)) | (0x2 << 62);
+
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        bits >> 96,
+        (bits >> 80) & 0xffff,
+        (bits >> 64) & 0xffff,
+        (bits >> 48) & 0xffff,
+        bits & 0xffff_ffff_ffff,
+    )
+}
+
+fn random_u64(seed: u64) -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(seed);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test_new_request_id {
+    use super::*;
+
+    #[test]
+    fn it_should_format_as_a_version_4_uuid() {
+        let request_id = new_request_id();
+        let groups: Vec<&str> = request_id.split('-').collect();
+
+        assert_eq!(request_id.len(), 36);
+        assert_eq!(
+            groups.iter().map(|group| group.len()).collect::<Vec<_>>(),
+            vec![8, 4, 4, 4, 12]
+        );
+        assert!(groups[2].starts_with('4'));
+        assert!(matches!(
+            groups[3].chars().next(),
+            Some('8' | '9' | 'a' | 'b')
+        ));
+    }
+
+    #[test]
+    fn it_should_be_unique_per_call() {
+        assert_ne!(new_request_id(), new_request_id());
+    }
+}
@@ -1,6 +1,10 @@
 use ::anyhow::Context;
 use ::anyhow::Result;
 use ::reserve_port::ReservedPort;
+use ::socket2::Domain;
+use ::socket2::Protocol;
+use ::socket2::Socket;
+use ::socket2::Type;
 use ::std::net::IpAddr;
 use ::std::net::Ipv4Addr;
 use ::std::net::SocketAddr;
@@ -8,6 +12,8 @@ use ::std::net::TcpListener;
 
 pub const DEFAULT_IP_ADDRESS: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
 
+const LISTEN_BACKLOG: i32 = 1024;
+
 pub struct StartingTcpSetup {
     pub maybe_reserved_port: Option<ReservedPort>,
     pub socket_addr: SocketAddr,
@@ -26,8 +32,9 @@ impl StartingTcpSetup {
     fn new_with_port(ip: IpAddr, port: u16) -> Result<Self> {
         ReservedPort::reserve_port(port)?;
         let socket_addr = SocketAddr::new(ip, port);
-        let tcp_listener = TcpListener::bind(socket_addr)
-            .with_context(|| "Failed to create TCPListener for TestServer")?;
+        let tcp_listener = bind_reusable_tcp_listener(socket_addr).with_context(|| {
+            format!("Failed to create TCPListener for TestServer, binding to {socket_addr}")
+        })?;
 
         Ok(Self {
             maybe_reserved_port: None,
@@ -48,6 +55,21 @@ impl StartingTcpSetup {
     }
 }
 
+/// Binds with `SO_REUSEADDR` set, so ports left in `TIME_WAIT`
+/// by a previous server can be bound again straight away.
+fn bind_reusable_tcp_listener(socket_addr: SocketAddr) -> Result<TcpListener> {
+    let socket = Socket::new(
+        Domain::for_address(socket_addr),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    socket.set_reuse_address(true)?;
+    socket.bind(&socket_addr.into())?;
+    socket.listen(LISTEN_BACKLOG)?;
+
+    Ok(socket.into())
+}
+
 #[cfg(test)]
 mod test_new {
     use super::*;
@@ -101,4 +123,15 @@ mod test_new {
 
         assert_eq!(addr, "127.0.0.1:8124");
     }
+
+    #[test]
+    fn it_should_include_address_in_bind_errors() {
+        let ip = Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+        let port = Some(8125);
+
+        let _setup = StartingTcpSetup::new(ip, port).unwrap();
+        let err = StartingTcpSetup::new(ip, port).err().unwrap();
+
+        assert!(format!("{err:?}").contains("binding to 127.0.0.1:8125"));
+    }
 }
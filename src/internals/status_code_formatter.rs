@@ -5,6 +5,7 @@ use ::std::fmt;
 pub struct StatusCodeFormatter(pub StatusCode);
 
 impl fmt::Display for StatusCodeFormatter {
+    #[allow(clippy::needless_borrow)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let code = self.0.as_u16();
         let reason = self.0.canonical_reason().unwrap_or(&"unknown status code");
@@ -4,17 +4,29 @@ use ::axum::body::Body;
 use ::bytes::Bytes;
 use ::http::response::Parts;
 use ::http::Request;
-use ::http_body_util::BodyExt;
+use ::http::Version;
+use ::hyper_util::client::legacy::connect::HttpConnector;
 use ::hyper_util::client::legacy::Client;
 use ::reserve_port::ReservedPort;
+use ::std::sync::Mutex;
+use ::std::time::Duration;
+use ::tokio::runtime::Handle;
+use ::tokio::sync::oneshot::Sender;
 use ::tokio::task::JoinHandle;
+use ::tokio::time::timeout;
 use ::url::Url;
 
+use crate::internals::collect_response;
+use crate::internals::max_response_body_size;
 use crate::transport_layer::TransportLayer;
 
 #[derive(Debug)]
 pub struct HttpTransportLayer {
-    server_handle: JoinHandle<()>,
+    server_task: Mutex<Option<ServerTask>>,
+
+    /// How long to wait for in-flight requests when shutting down.
+    /// When `None` the server is aborted straight away.
+    shutdown_timeout: Option<Duration>,
 
     /// If this has reserved a port for the test,
     /// then it is stored here.
@@ -24,41 +36,104 @@ pub struct HttpTransportLayer {
     maybe_reserved_port: Option<ReservedPort>,
 
     url: Url,
+
+    client: Client<HttpConnector, Body>,
+}
+
+/// The running server, and the signal to start it's graceful shutdown.
+#[derive(Debug)]
+struct ServerTask {
+    server_handle: JoinHandle<()>,
+    shutdown_sender: Sender<()>,
 }
 
 impl HttpTransportLayer {
     pub(crate) fn new(
         server_handle: JoinHandle<()>,
+        shutdown_sender: Sender<()>,
+        shutdown_timeout: Option<Duration>,
         maybe_reserved_port: Option<ReservedPort>,
         url: Url,
+        client: Client<HttpConnector, Body>,
     ) -> Self {
-        Self {
+        let server_task = ServerTask {
             server_handle,
+            shutdown_sender,
+        };
+
+        Self {
+            server_task: Mutex::new(Some(server_task)),
+            shutdown_timeout,
             maybe_reserved_port,
             url,
+            client,
         }
     }
+
+    fn take_server_task(&self) -> Option<ServerTask> {
+        self.server_task
+            .lock()
+            .map(|mut server_task| server_task.take())
+            .unwrap_or_default()
+    }
 }
 
 #[async_trait]
 impl TransportLayer for HttpTransportLayer {
-    async fn send(&mut self, request: Request<Body>) -> Result<(Parts, Bytes)> {
-        let client = Client::builder(hyper_util::rt::TokioExecutor::new()).build_http();
-        let hyper_response = client.request(request).await?;
+    async fn send(&self, mut request: Request<Body>) -> Result<(Parts, Bytes)> {
+        // The client negotiates the version used, so any set on the request is ignored.
+        *request.version_mut() = Version::default();
 
-        let (parts, response_body) = hyper_response.into_parts();
-        let response_bytes = response_body.collect().await?.to_bytes();
+        let max_body_size = max_response_body_size(&request);
+        let hyper_response = self.client.request(request).await?;
 
-        Ok((parts, response_bytes))
+        collect_response(hyper_response, max_body_size).await
     }
 
+    #[allow(clippy::needless_lifetimes)]
     fn url<'a>(&'a self) -> Option<&'a Url> {
         Some(&self.url)
     }
+
+    async fn shutdown(&self) {
+        if let Some(server_task) = self.take_server_task() {
+            shutdown_server_task(server_task, self.shutdown_timeout).await;
+        }
+    }
 }
 
 impl Drop for HttpTransportLayer {
     fn drop(&mut self) {
-        self.server_handle.abort()
+        let Some(server_task) = self.take_server_task() else {
+            return;
+        };
+
+        // Draining needs a runtime to wait on, so without one the server is aborted.
+        match (self.shutdown_timeout, Handle::try_current()) {
+            (Some(shutdown_timeout), Ok(runtime)) => {
+                runtime.spawn(shutdown_server_task(server_task, Some(shutdown_timeout)));
+            }
+            _ => server_task.server_handle.abort(),
+        }
+    }
+}
+
+/// Signals the server to stop accepting connections,
+/// and waits for in-flight requests up to the timeout given before aborting it.
+async fn shutdown_server_task(server_task: ServerTask, shutdown_timeout: Option<Duration>) {
+    let ServerTask {
+        server_handle,
+        shutdown_sender,
+    } = server_task;
+
+    let Some(shutdown_timeout) = shutdown_timeout else {
+        server_handle.abort();
+        return;
+    };
+
+    let _ = shutdown_sender.send(());
+    let abort_handle = server_handle.abort_handle();
+    if timeout(shutdown_timeout, server_handle).await.is_err() {
+        abort_handle.abort();
     }
 }
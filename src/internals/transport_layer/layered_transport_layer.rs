@@ -0,0 +1,76 @@
+use ::anyhow::Error as AnyhowError;
+use ::anyhow::Result;
+use ::async_trait::async_trait;
+use ::axum::body::Body;
+use ::bytes::Bytes;
+use ::http::response::Parts;
+use ::http::Request;
+use ::http::Response;
+use ::std::fmt::Debug;
+use ::std::sync::Arc;
+use ::tower::util::ServiceExt;
+use ::tower::Service;
+use ::url::Url;
+
+use crate::internals::collect_response;
+use crate::internals::max_response_body_size;
+use crate::transport_layer::TransportLayer;
+
+/// A transport which sends requests through a service,
+/// built by applying a `tower::Layer` on top of another transport.
+pub struct LayeredTransportLayer<S> {
+    service: S,
+    url: Option<Url>,
+
+    /// The transport underneath the layer, for shutting it down.
+    inner_transport: Arc<Box<dyn TransportLayer>>,
+}
+
+impl<S> LayeredTransportLayer<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + Sync,
+    AnyhowError: From<S::Error>,
+    S::Future: Send,
+{
+    pub(crate) fn new(
+        service: S,
+        url: Option<Url>,
+        inner_transport: Arc<Box<dyn TransportLayer>>,
+    ) -> Self {
+        Self {
+            service,
+            url,
+            inner_transport,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> TransportLayer for LayeredTransportLayer<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + Sync,
+    AnyhowError: From<S::Error>,
+    S::Future: Send,
+{
+    async fn send(&self, request: Request<Body>) -> Result<(Parts, Bytes)> {
+        let max_body_size = max_response_body_size(&request);
+        let service = self.service.clone();
+        let response = service.oneshot(request).await?;
+
+        collect_response(response, max_body_size).await
+    }
+
+    fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    async fn shutdown(&self) {
+        self.inner_transport.shutdown().await
+    }
+}
+
+impl<S> Debug for LayeredTransportLayer<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LayeredTransportLayer {{ service: {{unknown}} }}")
+    }
+}
@@ -1,20 +1,27 @@
+use ::anyhow::anyhow;
 use ::anyhow::Error as AnyhowError;
 use ::anyhow::Result;
 use ::async_trait::async_trait;
 use ::axum::body::Body;
 use ::axum::Router;
 use ::bytes::Bytes;
+use ::futures_util::FutureExt;
 use ::http::response::Parts;
 use ::http::Request;
-use ::http_body_util::BodyExt;
 use ::std::fmt::Debug;
+use ::std::panic::AssertUnwindSafe;
+use ::std::sync::Mutex;
 use ::tower::util::ServiceExt;
 use ::tower::Service;
 
+use crate::internals::collect_response;
+use crate::internals::max_response_body_size;
+use crate::internals::HandlerPanicError;
 use crate::transport_layer::TransportLayer;
 
+/// Locking the service, to clone it out, means it only needs to be `Send`.
 pub struct MockTransportLayer<S> {
-    service: S,
+    service: Mutex<S>,
 }
 
 impl<S> MockTransportLayer<S>
@@ -24,7 +31,9 @@ where
     S::Future: Send,
 {
     pub(crate) fn new(service: S) -> Self {
-        Self { service }
+        Self {
+            service: Mutex::new(service),
+        }
     }
 }
 
@@ -35,20 +44,26 @@ where
     AnyhowError: From<S::Error>,
     S::Future: Send,
 {
-    async fn send(&mut self, request: Request<Body>) -> Result<(Parts, Bytes)> {
+    async fn send(&self, request: Request<Body>) -> Result<(Parts, Bytes)> {
+        let max_body_size = max_response_body_size(&request);
         let body: Body = Bytes::new().into();
         let empty_request = Request::builder()
             .body(body)
             .expect("should build empty request");
 
-        let service = self.service.clone();
+        let service = self
+            .service
+            .lock()
+            .map_err(|err| anyhow!("Failed to lock the service, {err:?}"))?
+            .clone();
         let router = service.oneshot(empty_request).await?;
 
-        let response = router.oneshot(request).await?;
-        let (parts, response_body) = response.into_parts();
-        let response_bytes = response_body.collect().await?.to_bytes();
+        let response = AssertUnwindSafe(router.oneshot(request))
+            .catch_unwind()
+            .await
+            .map_err(HandlerPanicError::from_panic)??;
 
-        Ok((parts, response_bytes))
+        collect_response(response, max_body_size).await
     }
 }
 
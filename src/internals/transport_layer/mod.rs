@@ -3,3 +3,9 @@ pub use self::http_transport_layer::*;
 
 mod mock_transport_layer;
 pub use self::mock_transport_layer::*;
+
+mod layered_transport_layer;
+pub use self::layered_transport_layer::*;
+
+mod service_transport_layer;
+pub use self::service_transport_layer::*;
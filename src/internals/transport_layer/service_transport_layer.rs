@@ -0,0 +1,93 @@
+use ::anyhow::anyhow;
+use ::anyhow::Error as AnyhowError;
+use ::anyhow::Result;
+use ::async_trait::async_trait;
+use ::axum::body::Body;
+use ::bytes::Bytes;
+use ::futures_util::FutureExt;
+use ::http::response::Parts;
+use ::http::Request;
+use ::http::Response;
+use ::std::fmt::Debug;
+use ::std::panic::AssertUnwindSafe;
+use ::std::sync::Mutex;
+use ::tower::util::ServiceExt;
+use ::tower::Service;
+
+use crate::internals::collect_response;
+use crate::internals::max_response_body_size;
+use crate::internals::HandlerPanicError;
+use crate::transport_layer::IntoTransportLayer;
+use crate::transport_layer::TransportLayer;
+use crate::transport_layer::TransportLayerBuilder;
+
+/// A transport which passes requests directly to a `tower::Service`,
+/// for testing services which are not a `Router`.
+///
+/// The service is held within a `Mutex`, so it does not need to be `Sync`.
+/// It is cloned out for each request.
+pub struct ServiceTransportLayer<S> {
+    service: Mutex<S>,
+}
+
+impl<S> ServiceTransportLayer<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    AnyhowError: From<S::Error>,
+    S::Future: Send,
+{
+    pub(crate) fn new(service: S) -> Self {
+        Self {
+            service: Mutex::new(service),
+        }
+    }
+}
+
+#[async_trait]
+impl<S> TransportLayer for ServiceTransportLayer<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    AnyhowError: From<S::Error>,
+    S::Future: Send,
+{
+    async fn send(&self, request: Request<Body>) -> Result<(Parts, Bytes)> {
+        let max_body_size = max_response_body_size(&request);
+        let service = self
+            .service
+            .lock()
+            .map_err(|err| anyhow!("Failed to lock the service, {err:?}"))?
+            .clone();
+        let response = AssertUnwindSafe(service.oneshot(request))
+            .catch_unwind()
+            .await
+            .map_err(HandlerPanicError::from_panic)??;
+
+        collect_response(response, max_body_size).await
+    }
+}
+
+impl<S> IntoTransportLayer for ServiceTransportLayer<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    AnyhowError: From<S::Error>,
+    S::Future: Send,
+{
+    fn into_http_transport_layer(
+        self,
+        _builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        Err(anyhow!(
+            "Running a `tower::Service` on a real port is not supported, use the mock transport"
+        ))
+    }
+
+    fn into_mock_transport_layer(self) -> Result<Box<dyn TransportLayer>> {
+        Ok(Box::new(self))
+    }
+}
+
+impl<S> Debug for ServiceTransportLayer<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ServiceTransportLayer {{ service: {{unknown}} }}")
+    }
+}
@@ -192,9 +192,15 @@ pub use self::test_server_config_builder::*;
 mod test_server_config;
 pub use self::test_server_config::*;
 
+mod http_client_config;
+pub use self::http_client_config::*;
+
 mod test_request;
 pub use self::test_request::*;
 
+mod test_path;
+pub use self::test_path::*;
+
 mod test_response;
 pub use self::test_response::*;
 
@@ -203,7 +209,15 @@ pub mod util;
 
 pub use ::http;
 
+#[cfg(feature = "macros")]
+pub use ::axum_test_macros::test;
+
 #[cfg(test)]
+#[allow(
+    clippy::needless_borrow,
+    clippy::needless_borrows_for_generic_args,
+    clippy::redundant_static_lifetimes
+)]
 mod integrated_test_cookie_saving {
     use super::*;
 
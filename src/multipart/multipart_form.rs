@@ -1,8 +1,11 @@
+use ::anyhow::Context;
 use ::axum::body::Body as AxumBody;
 use ::rust_multipart_rfc7578_2::client::multipart::Body as CommonMultipartBody;
 use ::rust_multipart_rfc7578_2::client::multipart::Form;
 use ::std::fmt::Display;
+use ::std::fs::File;
 use ::std::io::Cursor;
+use ::std::path::Path;
 
 use crate::multipart::Part;
 
@@ -11,6 +14,7 @@ pub struct MultipartForm {
 }
 
 impl MultipartForm {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             inner: Form::default(),
@@ -41,6 +45,33 @@ impl MultipartForm {
         self
     }
 
+    /// Adds a file part, which is streamed from disk as the request is sent.
+    /// This is for uploading large files, without loading them into memory first.
+    ///
+    /// The part uses the file's name, and the mime type `application/octet-stream`.
+    /// The request is sent without a `Content-Length`,
+    /// as the size of the encoded form is not known up front.
+    ///
+    /// This will panic if the file cannot be opened.
+    pub fn add_file_stream<N, P>(mut self, name: N, path: P) -> Self
+    where
+        N: Display,
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .with_context(|| format!("Opening file {path:?} for multipart form"))
+            .unwrap();
+        let file_name = path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string());
+
+        self.inner
+            .add_reader_2(name, file, file_name, Some(mime::APPLICATION_OCTET_STREAM));
+
+        self
+    }
+
     /// Returns the content type this form will use when it is sent.
     pub fn content_type(&self) -> String {
         self.inner.content_type()
@@ -0,0 +1,106 @@
+#[doc(hidden)]
+pub use crate::internals::encode_path_segment as __encode_path_segment;
+
+#[doc(hidden)]
+pub use crate::internals::has_inline_path_capture as __has_inline_path_capture;
+
+///
+/// Builds a path for a request, percent-encoding each of the arguments.
+///
+/// This takes a format string in the same style as `format!`,
+/// where each `{}` is replaced with one of the arguments.
+/// The arguments are encoded so they can be safely used as a single path segment,
+/// whilst the rest of the path is left intact.
+/// A literal `{` or `}` can be written by doubling it, as `{{` or `}}`.
+///
+/// ```rust
+/// use ::axum_test::test_path;
+///
+/// let user_id = "joe bloggs";
+/// let post_id = "a/b?c";
+///
+/// let path = test_path!("/users/{}/posts/{}", user_id, post_id);
+/// assert_eq!(path, "/users/joe%20bloggs/posts/a%2Fb%3Fc");
+/// ```
+///
+/// Inline captures, such as `{user_id}`, are rejected at compile time,
+/// as they would be inserted without being encoded.
+/// Pass the value as an argument instead.
+///
+/// ```rust,compile_fail
+/// use ::axum_test::test_path;
+///
+/// let user_id = "joe bloggs";
+/// let path = test_path!("/users/{user_id}");
+/// ```
+///
+#[macro_export]
+macro_rules! test_path {
+    ($path:literal $(, $arg:expr)* $(,)?) => {{
+        const _: () = ::std::assert!(
+            !$crate::__has_inline_path_capture($path),
+            "test_path! does not encode inline captures, pass the values as arguments instead"
+        );
+
+        ::std::format!($path, $($crate::__encode_path_segment(&$arg)),*)
+    }};
+}
+
+#[cfg(test)]
+mod test_test_path {
+    use crate::TestServer;
+
+    use ::axum::extract::Path;
+    use ::axum::routing::get;
+    use ::axum::Router;
+
+    #[test]
+    fn it_should_encode_reserved_characters_in_arguments() {
+        let path = test_path!("/users/{}/posts/{}", "a/b", "c?d=e&f#g");
+
+        assert_eq!(path, "/users/a%2Fb/posts/c%3Fd%3De%26f%23g");
+    }
+
+    #[test]
+    fn it_should_leave_unreserved_characters_intact() {
+        let path = test_path!("/files/{}", "my-file_1.0~draft");
+
+        assert_eq!(path, "/files/my-file_1.0~draft");
+    }
+
+    #[test]
+    fn it_should_support_non_string_arguments() {
+        let path = test_path!("/users/{}", 123);
+
+        assert_eq!(path, "/users/123");
+    }
+
+    #[test]
+    fn it_should_allow_escaping_literal_braces() {
+        let path = test_path!("/templates/{{}}/{}", "a b");
+
+        assert_eq!(path, "/templates/{}/a%20b");
+    }
+
+    #[test]
+    fn it_should_support_paths_without_arguments() {
+        let path = test_path!("/users");
+
+        assert_eq!(path, "/users");
+    }
+
+    #[tokio::test]
+    async fn it_should_send_encoded_arguments_to_the_handler() {
+        async fn route_get_user(Path(name): Path<String>) -> String {
+            name
+        }
+
+        let app = Router::new().route("/users/:name", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get(&test_path!("/users/{}", "joe/bloggs?"))
+            .await
+            .assert_text("joe/bloggs?");
+    }
+}
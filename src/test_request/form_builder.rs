@@ -0,0 +1,63 @@
+use ::std::fmt::Display;
+
+use crate::TestRequest;
+
+///
+/// For building a urlencoded form body, one field at a time.
+///
+/// This is created by calling [`TestRequest::form_builder()`](crate::TestRequest::form_builder()),
+/// and then [`FormBuilder::build()`](crate::FormBuilder::build()) returns the request with the form set as it's body.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// use ::axum::Router;
+/// use ::axum_test::TestServer;
+///
+/// let app = Router::new();
+/// let server = TestServer::new(app)?;
+///
+/// let response = server.post(&"/my-form")
+///     .form_builder()
+///     .field("name", "Joe")
+///     .field("animals", "foxes")
+///     .build()
+///     .await;
+/// #
+/// # Ok(()) }
+/// ```
+///
+#[derive(Debug)]
+#[must_use = "the form is only set on the request when `build` is called"]
+pub struct FormBuilder {
+    request: TestRequest,
+    fields: Vec<(String, String)>,
+}
+
+impl FormBuilder {
+    pub(crate) fn new(request: TestRequest) -> Self {
+        Self {
+            request,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field to be sent in the form.
+    ///
+    /// Fields are sent in the order they are added,
+    /// and adding the same name twice will send both values.
+    pub fn field<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Display,
+        V: ToString,
+    {
+        self.fields.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the fields as the body of the request,
+    /// with the content type of 'application/x-www-form-urlencoded'.
+    pub fn build(self) -> TestRequest {
+        self.request.form(&self.fields)
+    }
+}
@@ -0,0 +1,89 @@
+use ::axum::body::Body;
+use ::axum::Error as AxumError;
+use ::bytes::Bytes;
+use ::futures_util::stream;
+use ::futures_util::StreamExt;
+use ::std::sync::atomic::AtomicUsize;
+use ::std::sync::atomic::Ordering;
+use ::std::sync::Arc;
+use ::std::time::Duration;
+use ::tokio::time::sleep;
+
+/// The body set on a `TestRequest`.
+///
+/// Bodies built from bytes are kept as bytes until the request is sent,
+/// so they can still be inspected before then.
+#[derive(Debug)]
+pub(crate) enum TestRequestBody {
+    Bytes(Bytes),
+    Stream(Body),
+}
+
+impl TestRequestBody {
+    pub(crate) fn as_bytes(&self) -> Option<&Bytes> {
+        match self {
+            Self::Bytes(bytes) => Some(bytes),
+            Self::Stream(_) => None,
+        }
+    }
+
+    pub(crate) fn into_body(self) -> Body {
+        match self {
+            Self::Bytes(bytes) => Body::from(bytes),
+            Self::Stream(body) => body,
+        }
+    }
+}
+
+/// The size of each chunk sent, when a body is sent with a delay.
+const DELAYED_CHUNK_SIZE: usize = 1024;
+
+/// Wraps the body given, so it is streamed in chunks
+/// with the delay given before each chunk is sent.
+pub(crate) fn delay_body_chunks(body: Body, per_chunk: Duration) -> Body {
+    let chunks = body.into_data_stream().flat_map(|frame| {
+        let chunks: Vec<Result<Bytes, AxumError>> = match frame {
+            Err(err) => vec![Err(err)],
+            Ok(mut data) => {
+                let mut chunks = Vec::new();
+                while data.len() > DELAYED_CHUNK_SIZE {
+                    chunks.push(Ok(data.split_to(DELAYED_CHUNK_SIZE)));
+                }
+                chunks.push(Ok(data));
+                chunks
+            }
+        };
+
+        stream::iter(chunks)
+    });
+
+    let delayed_chunks = chunks.then(move |chunk| async move {
+        sleep(per_chunk).await;
+        chunk
+    });
+
+    Body::from_stream(delayed_chunks)
+}
+
+/// Wraps the body given, so it fails once more than `max_size` bytes are read from it.
+///
+/// The number of bytes read so far is recorded in the counter returned,
+/// allowing the size to be checked after the request is sent.
+pub(crate) fn limit_body_size(body: Body, max_size: usize) -> (Body, Arc<AtomicUsize>) {
+    let bytes_read = Arc::new(AtomicUsize::new(0));
+    let bytes_read_counter = bytes_read.clone();
+
+    let limited_chunks = body.into_data_stream().map(move |frame| {
+        let data = frame?;
+        let total_read = bytes_read_counter.fetch_add(data.len(), Ordering::SeqCst) + data.len();
+        if total_read > max_size {
+            return Err(AxumError::new(format!(
+                "Request body exceeds the max request body size of {max_size} bytes"
+            )));
+        }
+
+        Ok(data)
+    });
+
+    (Body::from_stream(limited_chunks), bytes_read)
+}
@@ -1,3 +1,7 @@
+use ::http::HeaderName;
+use ::http::Version;
+use ::std::net::SocketAddr;
+use ::std::time::Duration;
 use ::url::Url;
 
 use crate::internals::ExpectedState;
@@ -8,6 +12,20 @@ pub struct TestRequestConfig {
     pub is_saving_cookies: bool,
     pub expected_state: ExpectedState,
     pub content_type: Option<String>,
+    pub user_agent: Option<String>,
+    pub accept: Option<String>,
+    pub max_body_size: Option<usize>,
+    pub max_response_body_size: Option<usize>,
+    pub is_handler_panic_500: bool,
+    pub is_body_in_assertion_errors: bool,
+    pub is_content_type_required: bool,
+    pub is_warning_on_get_body: bool,
+    pub is_panicking_on_unconsumed_response: bool,
+    pub connect_info: Option<SocketAddr>,
+    pub http_version: Option<Version>,
+    pub request_id_header: Option<HeaderName>,
+    pub is_following_redirects: bool,
+    pub body_send_delay: Option<Duration>,
     pub full_request_url: Url,
     pub request_format: RequestPathFormatter,
 }
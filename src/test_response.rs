@@ -4,22 +4,70 @@ use ::cookie::Cookie;
 use ::cookie::CookieJar;
 use ::http::header::AsHeaderName;
 use ::http::header::HeaderName;
+use ::http::header::CACHE_CONTROL;
+use ::http::header::CONTENT_RANGE;
+use ::http::header::CONTENT_TYPE;
 use ::http::header::SET_COOKIE;
 use ::http::response::Parts;
 use ::http::HeaderMap;
 use ::http::HeaderValue;
+use ::http::Method;
 use ::http::StatusCode;
 use ::serde::de::DeserializeOwned;
+use ::serde::Serialize;
+use ::serde_json::Value;
+use ::std::cmp::Ordering;
+use ::std::collections::HashMap;
 use ::std::convert::AsRef;
 use ::std::fmt::Debug;
 use ::std::fmt::Display;
+use ::std::fs;
+use ::std::io::Result as IoResult;
+use ::std::path::Path;
+use ::std::str::FromStr;
+use ::std::str::Utf8Error;
+use ::std::sync::OnceLock;
 use ::url::Url;
 
 #[cfg(feature = "pretty-assertions")]
-use ::pretty_assertions::{assert_eq, assert_ne};
-
+use ::pretty_assertions::assert_eq;
+#[cfg(feature = "pretty-assertions")]
+use ::pretty_assertions::assert_ne;
+
+#[cfg(feature = "regex")]
+use ::regex::Regex;
+
+use crate::internals::canonical_json;
+use crate::internals::find_json_mismatch;
+use crate::internals::find_json_subset_mismatches;
+use crate::internals::get_json_path;
+use crate::internals::is_removal_cookie;
+use crate::internals::merge_cookie_into_jar;
+use crate::internals::parse_cache_control;
+use crate::internals::remove_json_path;
 use crate::internals::RequestPathFormatter;
 use crate::internals::StatusCodeFormatter;
+use crate::TestServer;
+
+mod assertion_error;
+pub use self::assertion_error::*;
+
+mod cookie_changes;
+pub use self::cookie_changes::*;
+
+mod content_range;
+pub use self::content_range::*;
+
+mod inspection_guard;
+use self::inspection_guard::InspectionGuard;
+
+const PLACEHOLDER_REQUEST_URL: &str = "http://localhost/";
+
+const UPDATE_GOLDEN_ENV_VAR: &str = "UPDATE_GOLDEN";
+
+const JSON_ERROR_CODE_PATH: &str = "error.code";
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
 
 ///
 /// The `TestResponse` is the result of a request created using a [`TestServer`](crate::TestServer).
@@ -119,34 +167,130 @@ use crate::internals::StatusCodeFormatter;
 /// # }
 /// ```
 ///
+/// # Json Paths
+///
+/// Assertions which take a Json path use keys separated by dots,
+/// with array indexes in square brackets, such as `"users[0].name"`.
+/// An index can also be used at the root, such as `"[0].name"`.
+///
+/// This is the same format used when reporting where Json does not match,
+/// so a path from a failure message can be passed straight to these assertions.
+///
 #[derive(Clone, Debug)]
 pub struct TestResponse {
     request_format: RequestPathFormatter,
 
     /// This is the actual url that was used for the request.
+    /// When following redirects, this is the url of the final request.
     full_request_url: Url,
+
+    /// This is the url first requested, before following any redirects.
+    original_request_url: Url,
+
+    /// The id generated for the request, when using `auto_request_id`.
+    request_id: Option<String>,
+
+    /// The cookies that were sent up with the request.
+    request_cookies: CookieJar,
+
     headers: HeaderMap<HeaderValue>,
     status_code: StatusCode,
     response_body: Bytes,
+
+    /// Any trailers sent after the body of the response.
+    response_trailers: Option<HeaderMap>,
+
+    /// The body parsed as Json, populated on first use.
+    parsed_json: OnceLock<Value>,
+
+    /// For panicking when the response is dropped without being inspected.
+    inspection_guard: InspectionGuard,
 }
 
 impl TestResponse {
     pub(crate) fn new(
         request_format: RequestPathFormatter,
         full_request_url: Url,
+        request_cookies: CookieJar,
         parts: Parts,
         response_body: Bytes,
+        response_trailers: Option<HeaderMap>,
     ) -> Self {
         Self {
+            inspection_guard: InspectionGuard::new(request_format.clone()),
             request_format,
+            original_request_url: full_request_url.clone(),
             full_request_url,
+            request_id: None,
+            request_cookies,
             headers: parts.headers,
             status_code: parts.status,
             response_body,
+            response_trailers,
+            parsed_json: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn with_original_request_url(mut self, original_request_url: Url) -> Self {
+        self.original_request_url = original_request_url;
+        self
+    }
+
+    pub(crate) fn with_request_id(mut self, request_id: Option<String>) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    pub(crate) fn with_inspection_required(mut self, is_required: bool) -> Self {
+        if is_required {
+            self.inspection_guard.require_inspection();
+        }
+        self
+    }
+
+    fn mark_inspected(&self) {
+        self.inspection_guard.mark_inspected();
+    }
+
+    /// Creates a `TestResponse` directly from it's parts, without sending a request.
+    ///
+    /// This is for testing the assertions in isolation,
+    /// such as when writing helpers which build on top of this crate.
+    ///
+    /// As there was no request, the request named in panic messages
+    /// is a placeholder of `GET <unknown>`, and the request url is `http://localhost/`.
+    ///
+    /// ```rust
+    /// use ::axum_test::TestResponse;
+    /// use ::bytes::Bytes;
+    /// use ::http::HeaderMap;
+    /// use ::http::StatusCode;
+    ///
+    /// let response = TestResponse::from_parts(StatusCode::OK, HeaderMap::new(), Bytes::from("hello!"));
+    ///
+    /// response.assert_status_ok();
+    /// response.assert_text("hello!");
+    /// ```
+    pub fn from_parts(status_code: StatusCode, headers: HeaderMap, response_body: Bytes) -> Self {
+        let request_format = RequestPathFormatter::new(Method::GET, "<unknown>".to_string());
+
+        Self {
+            inspection_guard: InspectionGuard::new(request_format.clone()),
+            request_format,
+            full_request_url: PLACEHOLDER_REQUEST_URL.parse().unwrap(),
+            original_request_url: PLACEHOLDER_REQUEST_URL.parse().unwrap(),
+            request_id: None,
+            request_cookies: CookieJar::new(),
+            headers,
+            status_code,
+            response_body,
+            response_trailers: None,
+            parsed_json: OnceLock::new(),
         }
     }
 
     /// Returns the underlying response, extracted as a UTF-8 string.
+    /// A leading UTF-8 byte order mark is removed.
     ///
     /// # Example
     ///
@@ -181,12 +325,31 @@ impl TestResponse {
     /// ```
     #[must_use]
     pub fn text(&self) -> String {
-        String::from_utf8_lossy(&self.as_bytes()).to_string()
+        String::from_utf8_lossy(self.body_without_bom()).to_string()
+    }
+
+    /// Returns the response body as UTF-8 text, split into lines.
+    ///
+    /// Lines can end with either `\n` or `\r\n`, and the line endings are not included.
+    /// A trailing line ending does not produce an extra empty line.
+    #[must_use]
+    pub fn text_lines(&self) -> Vec<String> {
+        self.text().lines().map(ToString::to_string).collect()
+    }
+
+    /// Returns the response body as a borrowed `&str`, without allocating.
+    ///
+    /// Unlike [`TestResponse::text()`](crate::TestResponse::text()),
+    /// this returns an error if the body is not valid UTF-8.
+    /// A leading UTF-8 byte order mark in the body is ignored.
+    pub fn as_str(&self) -> Result<&str, Utf8Error> {
+        ::std::str::from_utf8(self.body_without_bom())
     }
 
     /// Deserializes the response, as Json, into the type given.
     ///
     /// If deserialization fails then this will panic.
+    /// A leading UTF-8 byte order mark in the body is ignored.
     ///
     /// # Example
     ///
@@ -229,7 +392,7 @@ impl TestResponse {
     where
         T: DeserializeOwned,
     {
-        serde_json::from_slice::<T>(&self.as_bytes())
+        serde_json::from_slice::<T>(self.body_without_bom())
             .with_context(|| {
                 let request_format = &self.request_format;
 
@@ -238,9 +401,23 @@ impl TestResponse {
             .unwrap()
     }
 
+    /// Returns the response body parsed as a Json [`Value`](::serde_json::Value).
+    ///
+    /// The body is only parsed on the first call, and the result is kept for later calls.
+    /// The `assert_json_*` functions which work on Json values share this,
+    /// so asserting several times against a response only parses it once.
+    ///
+    /// If the body is not valid Json then this will panic.
+    #[must_use]
+    pub fn json_value(&self) -> &Value {
+        self.mark_inspected();
+        self.parsed_json.get_or_init(|| self.json::<Value>())
+    }
+
     /// Deserializes the response, as Yaml, into the type given.
     ///
     /// If deserialization fails then this will panic.
+    /// A leading UTF-8 byte order mark in the body is ignored.
     ///
     /// # Example
     ///
@@ -284,7 +461,7 @@ impl TestResponse {
     where
         T: DeserializeOwned,
     {
-        serde_yaml::from_slice::<T>(&self.as_bytes())
+        serde_yaml::from_slice::<T>(self.body_without_bom())
             .with_context(|| {
                 let request_format = &self.request_format;
 
@@ -296,6 +473,7 @@ impl TestResponse {
     /// Deserializes the response, as an urlencoded Form, into the type given.
     ///
     /// If deserialization fails then this will panic.
+    /// A leading UTF-8 byte order mark in the body is ignored.
     ///
     /// # Example
     ///
@@ -338,7 +516,7 @@ impl TestResponse {
     where
         T: DeserializeOwned,
     {
-        serde_urlencoded::from_bytes::<T>(&self.as_bytes())
+        serde_urlencoded::from_bytes::<T>(self.body_without_bom())
             .with_context(|| {
                 let request_format = &self.request_format;
 
@@ -347,31 +525,232 @@ impl TestResponse {
             .unwrap()
     }
 
+    /// Deserializes the response body as a url encoded form,
+    /// after checking the `Content-Type` is `application/x-www-form-urlencoded`.
+    ///
+    /// This is a stricter version of [`TestResponse::form()`](crate::TestResponse::form()),
+    /// which will panic if the content type is missing or does not match.
+    #[must_use]
+    pub fn strict_form<T>(&self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.mark_inspected();
+        let request_format = &self.request_format;
+        let expected_content_type = mime::APPLICATION_WWW_FORM_URLENCODED.essence_str();
+        let content_type = self
+            .headers
+            .get(CONTENT_TYPE)
+            .map(|header| String::from_utf8_lossy(header.as_bytes()).to_string())
+            .unwrap_or_else(|| {
+                panic!("Expected content type '{expected_content_type}', received no 'content-type' header, for request {request_format}")
+            });
+
+        let is_form = content_type
+            .parse::<mime::Mime>()
+            .is_ok_and(|mime| mime.essence_str() == expected_content_type);
+        if !is_form {
+            panic!("Expected content type '{expected_content_type}', received '{content_type}', for request {request_format}");
+        }
+
+        self.form::<T>()
+    }
+
+    /// Deserializes the response body, choosing how to decode it
+    /// based on the `Content-Type` header of the response.
+    ///
+    /// This supports Json (including types such as `application/problem+json`),
+    /// url encoded forms, and Yaml when the `yaml` feature is enabled.
+    ///
+    /// This will panic if the content type is missing or unsupported,
+    /// or if the body cannot be decoded.
+    #[must_use]
+    pub fn decode<T>(&self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.mark_inspected();
+        let request_format = &self.request_format;
+        let content_type = self
+            .headers
+            .get(CONTENT_TYPE)
+            .map(|header| String::from_utf8_lossy(header.as_bytes()).to_string())
+            .unwrap_or_else(|| {
+                panic!("Cannot decode response without a 'content-type' header, for request {request_format}")
+            });
+        let mime = content_type
+            .parse::<mime::Mime>()
+            .with_context(|| {
+                format!(
+                    "Parsing 'content-type' header '{content_type}', for request {request_format}"
+                )
+            })
+            .unwrap();
+
+        let is_json = mime.subtype() == mime::JSON || mime.suffix() == Some(mime::JSON);
+        if is_json {
+            return self.json::<T>();
+        }
+
+        if mime.essence_str() == mime::APPLICATION_WWW_FORM_URLENCODED.essence_str() {
+            return self.form::<T>();
+        }
+
+        #[cfg(feature = "yaml")]
+        {
+            let is_yaml = matches!(mime.subtype().as_str(), "yaml" | "x-yaml")
+                || mime
+                    .suffix()
+                    .is_some_and(|suffix| suffix.as_str() == "yaml");
+            if is_yaml {
+                return self.yaml::<T>();
+            }
+        }
+
+        panic!("Cannot decode response with unsupported content type '{content_type}', for request {request_format}")
+    }
+
     /// Returns the raw underlying response as `Bytes`.
     #[must_use]
+    #[allow(clippy::needless_lifetimes)]
     pub fn as_bytes<'a>(&'a self) -> &'a Bytes {
+        self.mark_inspected();
         &self.response_body
     }
 
+    /// Returns the body, with any leading UTF-8 byte order mark removed.
+    /// Some services prefix their responses with one,
+    /// which breaks parsing the body as text, Json, and other formats.
+    fn body_without_bom(&self) -> &[u8] {
+        self.mark_inspected();
+        let body = self.response_body.as_ref();
+        body.strip_prefix(UTF8_BOM).unwrap_or(body)
+    }
+
     /// Consumes this returning the underlying `Bytes`
     /// in the response.
     #[must_use]
+    #[allow(clippy::extra_unused_lifetimes)]
     pub fn into_bytes<'a>(self) -> Bytes {
+        self.mark_inspected();
         self.response_body
     }
 
+    /// Consumes this returning the response body as a UTF-8 string.
+    ///
+    /// This is the same as [`TestResponse::text()`](crate::TestResponse::text()),
+    /// for when the response is no longer needed.
+    #[must_use]
+    pub fn into_text(self) -> String {
+        self.text()
+    }
+
+    /// Consumes this, deserializing the response body as Json.
+    ///
+    /// This is the same as [`TestResponse::json()`](crate::TestResponse::json()),
+    /// for when only the typed value is needed.
+    #[must_use]
+    pub fn into_json<T>(self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.json::<T>()
+    }
+
+    /// Consumes this, deserializing the response body as a url encoded form.
+    ///
+    /// This is the same as [`TestResponse::form()`](crate::TestResponse::form()),
+    /// for when only the typed value is needed.
+    #[must_use]
+    pub fn into_form<T>(self) -> T
+    where
+        T: DeserializeOwned,
+    {
+        self.form::<T>()
+    }
+
+    /// Writes the raw underlying response body to the file given.
+    ///
+    /// This is intended for debugging binary responses, such as images,
+    /// where the file can then be inspected by hand.
+    pub fn save_to_file<P>(&self, path: P) -> IoResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        self.mark_inspected();
+        fs::write(path, &self.response_body)
+    }
+
     /// The status_code of the response.
     #[must_use]
     pub fn status_code(&self) -> StatusCode {
+        self.mark_inspected();
         self.status_code
     }
 
+    /// Returns the status code, headers, and body of the response, in one go.
+    ///
+    /// This is for destructuring the whole response in one line, such as for logging.
+    #[must_use]
+    pub fn status_headers_body(&self) -> (StatusCode, &HeaderMap<HeaderValue>, &Bytes) {
+        self.mark_inspected();
+        (self.status_code, &self.headers, &self.response_body)
+    }
+
     /// The full URL that was used to produce this response.
+    ///
+    /// When following redirects, this is the URL of the final request.
     #[must_use]
     pub fn request_url(&self) -> Url {
+        self.mark_inspected();
         self.full_request_url.clone()
     }
 
+    /// The full URL first requested, before following any redirects.
+    ///
+    /// When not following redirects, this is the same as
+    /// [`TestResponse::request_url()`](crate::TestResponse::request_url()).
+    #[must_use]
+    pub fn original_request_url(&self) -> Url {
+        self.mark_inspected();
+        self.original_request_url.clone()
+    }
+
+    /// The id sent in the request id header,
+    /// when [`TestServerConfig::auto_request_id`](crate::TestServerConfig::auto_request_id) is set.
+    ///
+    /// Returns `None` when no request id header is configured.
+    #[must_use]
+    pub fn request_id(&self) -> Option<String> {
+        self.mark_inspected();
+        self.request_id.clone()
+    }
+
+    /// Asserts the URL of the final request matches the one given,
+    /// such as after following redirects.
+    ///
+    /// The URL given can be a full URL, or a path (with any query) relative to the server.
+    #[track_caller]
+    pub fn assert_final_url(&self, expected: &str) {
+        self.mark_inspected();
+        let final_url = &self.full_request_url;
+        let is_matching = match expected.starts_with('/') {
+            true => {
+                let path_and_query = match final_url.query() {
+                    Some(query) => format!("{}?{query}", final_url.path()),
+                    None => final_url.path().to_string(),
+                };
+                path_and_query == expected
+            }
+            false => final_url.as_str() == expected,
+        };
+
+        if !is_matching {
+            let request_format = &self.request_format;
+            panic!("Expected final url '{expected}', received '{final_url}', for request {request_format}");
+        }
+    }
+
     /// Finds a header with the given name.
     /// If there are multiple headers with the same name,
     /// then only the first [`HeaderValue`](::http::HeaderValue) will be returned.
@@ -382,12 +761,15 @@ impl TestResponse {
     where
         N: AsHeaderName,
     {
+        self.mark_inspected();
         self.headers.get(header_name).map(|h| h.to_owned())
     }
 
     /// Returns the headers returned from the response.
     #[must_use]
+    #[allow(clippy::needless_lifetimes)]
     pub fn headers<'a>(&'a self) -> &'a HeaderMap<HeaderValue> {
+        self.mark_inspected();
         &self.headers
     }
 
@@ -401,6 +783,7 @@ impl TestResponse {
     where
         N: AsHeaderName + Display + Clone,
     {
+        self.mark_inspected();
         let debug_header = header_name.clone();
         self.headers
             .get(header_name)
@@ -413,12 +796,64 @@ impl TestResponse {
             .unwrap()
     }
 
+    /// Finds a header with the given name, and parses it's value into the type given.
+    /// This is useful for numeric headers, such as `Retry-After` or `X-Total-Count`.
+    ///
+    /// `None` is returned when no header was found,
+    /// or if the value could not be parsed.
+    #[must_use]
+    pub fn header_as<T, N>(&self, header_name: N) -> Option<T>
+    where
+        T: FromStr,
+        N: AsHeaderName,
+    {
+        self.mark_inspected();
+        self.headers
+            .get(header_name)
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header_str| header_str.parse::<T>().ok())
+    }
+
+    /// Parses the `Content-Range` header of the response.
+    ///
+    /// `None` is returned when there is no header,
+    /// or if it is not a byte range such as `bytes 0-499/1234`.
+    #[must_use]
+    pub fn content_range(&self) -> Option<ContentRange> {
+        self.header_as::<ContentRange, _>(CONTENT_RANGE)
+    }
+
+    /// Finds a header with the given name, and parses it's value into the type given.
+    ///
+    /// If no header is found, or the value cannot be parsed, then this will panic.
+    #[must_use]
+    #[track_caller]
+    pub fn header_as_required<T, N>(&self, header_name: N) -> T
+    where
+        T: FromStr,
+        T::Err: Display,
+        N: AsHeaderName + Display + Clone,
+    {
+        let request_format = &self.request_format;
+        let header = self.header(header_name.clone());
+        let header_str = header.to_str().unwrap_or_else(|err| {
+            panic!("Failed to read header {header_name} as a string, {err}, for request {request_format}")
+        });
+
+        header_str.parse::<T>().unwrap_or_else(|err| {
+            panic!("Failed to parse header {header_name} with value '{header_str}', {err}, for request {request_format}")
+        })
+    }
+
     /// Iterates over all of the headers contained in the response.
+    #[allow(clippy::needless_lifetimes)]
     pub fn iter_headers<'a>(&'a self) -> impl Iterator<Item = (&'a HeaderName, &'a HeaderValue)> {
+        self.mark_inspected();
         self.headers.iter()
     }
 
     /// Iterates over all of the headers for a specific name, contained in the response.
+    #[allow(clippy::needless_lifetimes)]
     pub fn iter_headers_by_name<'a, N>(
         &'a self,
         header_name: N,
@@ -426,9 +861,46 @@ impl TestResponse {
     where
         N: AsHeaderName,
     {
+        self.mark_inspected();
         self.headers.get_all(header_name).iter()
     }
 
+    /// Returns the trailers sent after the body of the response.
+    ///
+    /// `None` is returned when the response had no trailers.
+    #[must_use]
+    pub fn trailers(&self) -> Option<&HeaderMap> {
+        self.mark_inspected();
+        self.response_trailers.as_ref()
+    }
+
+    /// Asserts a trailer with the given name was sent after the response body,
+    /// and that it matches the value given.
+    ///
+    /// If the trailer is missing, or has a different value, then this will panic.
+    #[track_caller]
+    pub fn assert_trailer<N, V>(&self, trailer_name: N, expected_value: V)
+    where
+        N: AsHeaderName + Display + Clone,
+        V: Debug,
+        HeaderValue: PartialEq<V>,
+    {
+        let debug_trailer = trailer_name.clone();
+        let request_format = &self.request_format;
+        let trailer_value = self
+            .trailers()
+            .and_then(|trailers| trailers.get(trailer_name))
+            .with_context(|| {
+                format!("Cannot find trailer {debug_trailer}, for request {request_format}")
+            })
+            .unwrap();
+
+        assert!(
+            *trailer_value == expected_value,
+            "Expected trailer '{debug_trailer}' to be {expected_value:?}, got {trailer_value:?}, for request {request_format}"
+        );
+    }
+
     /// Finds a [`Cookie`] with the given name.
     /// If there are multiple matching cookies,
     /// then only the first will be returned.
@@ -476,9 +948,73 @@ impl TestResponse {
         cookies
     }
 
+    /// Returns the cookies sent with the request,
+    /// with the cookies from this response applied on top.
+    ///
+    /// This is the jar as it would be after saving the cookies returned.
+    /// Cookies set by the response replace any with the same name,
+    /// and any removed by the response (i.e. with an expiry in the past)
+    /// will be removed from the jar.
+    ///
+    /// This is useful for debugging what cookies would be saved to the
+    /// [`TestServer`](crate::TestServer) after this response.
+    #[must_use]
+    pub fn cookies_jar_merged_with_request(&self) -> CookieJar {
+        self.mark_inspected();
+        let mut cookies = self.request_cookies.clone();
+
+        for cookie in self.iter_cookies() {
+            merge_cookie_into_jar(&mut cookies, cookie.into_owned());
+        }
+
+        cookies
+    }
+
+    /// Saves the cookies returned by this response onto the `TestServer` given,
+    /// to be sent on all of it's future requests.
+    ///
+    /// This is for when cookies from one server should seed another,
+    /// such as simulating a session shared across services.
+    /// Cookies removed by this response are also removed from the server.
+    pub fn apply_cookies_to(&self, server: &mut TestServer) {
+        self.mark_inspected();
+        server.add_cookies_by_header(self.headers.get_all(SET_COOKIE).iter());
+    }
+
+    /// Returns the cookies added, updated, and removed by this response,
+    /// compared to the cookies sent with the request.
+    ///
+    /// This is the change applied to the [`TestServer`](crate::TestServer)
+    /// when it is saving cookies. Such as checking a logout removes the session cookie.
+    ///
+    /// Cookies set to the same value they were sent with are not included.
+    #[must_use]
+    pub fn cookie_changes(&self) -> CookieChanges {
+        self.mark_inspected();
+        let mut changes = CookieChanges::default();
+
+        for cookie in self.iter_cookies() {
+            let cookie = cookie.into_owned();
+            let maybe_request_cookie = self.request_cookies.get(cookie.name());
+
+            match maybe_request_cookie {
+                Some(_) if is_removal_cookie(&cookie) => changes.push_removed(cookie),
+                Some(request_cookie) if request_cookie.value() != cookie.value() => {
+                    changes.push_updated(cookie)
+                }
+                Some(_) => {}
+                None if is_removal_cookie(&cookie) => {}
+                None => changes.push_added(cookie),
+            }
+        }
+
+        changes
+    }
+
     /// Iterate over all of the cookies in the response.
     #[must_use]
-    pub fn iter_cookies<'a>(&'a self) -> impl Iterator<Item = Cookie<'a>> {
+    #[allow(clippy::double_must_use)]
+    pub fn iter_cookies(&self) -> impl Iterator<Item = Cookie<'_>> {
         self.iter_headers_by_name(SET_COOKIE).map(|header| {
             let header_str = header
                 .to_str()
@@ -510,6 +1046,64 @@ impl TestResponse {
         assert_eq!(other_contents, &self.text());
     }
 
+    /// This performs an assertion that the body of the response is either empty,
+    /// or matches the text provided.
+    ///
+    /// This is for sharing an assertion between a `GET` and it's `HEAD` equivalent,
+    /// or any other response which may legitimately have no body (such as a `304`).
+    /// It should not be used for responses which are always expected to have content,
+    /// as it will hide bugs where the body is wrongly empty.
+    /// For those use [`TestResponse::assert_text()`](crate::TestResponse::assert_text()).
+    #[track_caller]
+    pub fn assert_text_or_empty(&self, expected: &str) {
+        self.mark_inspected();
+        if self.response_body.is_empty() {
+            return;
+        }
+
+        let received = self.text();
+        if received != expected {
+            let request_format = &self.request_format;
+            panic!("Expected response body to be empty or '{expected}', received '{received}', for request {request_format}");
+        }
+    }
+
+    /// Asserts the response body has the number of lines given,
+    /// using the same rules for splitting lines as
+    /// [`TestResponse::text_lines()`](crate::TestResponse::text_lines()).
+    #[track_caller]
+    pub fn assert_line_count(&self, expected: usize) {
+        let received = self.text_lines().len();
+        if received != expected {
+            let request_format = &self.request_format;
+            panic!("Expected response body to have {expected} lines, received {received} lines, for request {request_format}");
+        }
+    }
+
+    /// This performs an assertion comparing the whole body of the response,
+    /// byte for byte, against the bytes provided.
+    ///
+    /// On a mismatch this reports the offset of the first differing byte,
+    /// and the lengths of both bodies.
+    #[track_caller]
+    pub fn assert_bytes(&self, expected: &[u8]) {
+        let received = self.as_bytes().as_ref();
+        if received == expected {
+            return;
+        }
+
+        let offset = received
+            .iter()
+            .zip(expected)
+            .position(|(received_byte, expected_byte)| received_byte != expected_byte)
+            .unwrap_or_else(|| received.len().min(expected.len()));
+        let expected_len = expected.len();
+        let received_len = received.len();
+        let request_format = &self.request_format;
+
+        panic!("Expected response body to match the bytes given, first difference at offset {offset}, expected length {expected_len}, received length {received_len}, for request {request_format}");
+    }
+
     /// Deserializes the contents of the request as Json,
     /// and asserts it matches the value given.
     ///
@@ -523,658 +1117,4950 @@ impl TestResponse {
         assert_eq!(*other, self.json::<T>());
     }
 
-    /// Deserializes the contents of the request as Yaml,
+    /// Deserializes the contents of the request as Json,
     /// and asserts it matches the value given.
     ///
-    /// If `other` does not match, or the response is not Yaml,
-    /// then this will panic.
-    #[cfg(feature = "yaml")]
+    /// Numbers are treated as matching when they are within `epsilon` of each other.
+    /// This is useful for values calculated using floating point maths.
+    /// All other values must match exactly.
+    ///
+    /// If `expected` does not match, or the response is not Json,
+    /// then this will panic, reporting where in the Json the mismatch is.
     #[track_caller]
-    pub fn assert_yaml<T>(&self, other: &T)
-    where
-        T: DeserializeOwned + PartialEq<T> + Debug,
-    {
-        assert_eq!(*other, self.yaml::<T>());
+    pub fn assert_json_approx(&self, expected: &Value, epsilon: f64) {
+        let received = self.json_value();
+        let maybe_mismatch = find_json_mismatch(expected, received, |expected, received| {
+            match (expected.as_f64(), received.as_f64()) {
+                (Some(expected), Some(received)) => (expected - received).abs() <= epsilon,
+                _ => expected == received,
+            }
+        });
+
+        if let Some(mismatch) = maybe_mismatch {
+            let request_format = &self.request_format;
+            panic!("Expected Json to match within epsilon {epsilon}, {mismatch}, for request {request_format}");
+        }
     }
 
-    /// Deserializes the contents of the request as an url encoded form,
-    /// and asserts it matches the value given.
+    /// Deserializes the contents of the request as Json,
+    /// and asserts it is exactly the same as the value given.
     ///
-    /// If `other` does not match, or the response cannot be deserialized,
-    /// then this will panic.
+    /// This is the untyped version of [`TestResponse::assert_json()`](crate::TestResponse::assert_json()).
+    /// Objects are compared ignoring the order of their keys,
+    /// and arrays are compared in order.
+    ///
+    /// If `expected` does not match, or the response is not Json,
+    /// then this will panic, reporting where in the Json the mismatch is.
     #[track_caller]
-    pub fn assert_form<T>(&self, other: &T)
+    pub fn assert_json_exact(&self, expected: &Value) {
+        let received = self.json_value();
+
+        if let Some(mismatch) = find_json_mismatch(expected, received, |a, b| a == b) {
+            let request_format = &self.request_format;
+            panic!("Expected Json to match, {mismatch}, for request {request_format}");
+        }
+    }
+
+    /// Deserializes the contents of the request as Json,
+    /// and asserts it is exactly the same as the Json in the file given.
+    ///
+    /// This is useful for golden file testing, where expected responses are kept in files.
+    /// The comparison is the same as [`TestResponse::assert_json_exact()`](crate::TestResponse::assert_json_exact()).
+    ///
+    /// When the `UPDATE_GOLDEN` environment variable is set to `1`,
+    /// the file is instead overwritten with the Json of the response.
+    ///
+    /// This will panic if the file cannot be read or is not Json,
+    /// or if the Json does not match.
+    #[track_caller]
+    pub fn assert_json_matches_file<P>(&self, path: P)
     where
-        T: DeserializeOwned + PartialEq<T> + Debug,
+        P: AsRef<Path>,
     {
-        assert_eq!(*other, self.form::<T>());
+        let is_updating = ::std::env::var(UPDATE_GOLDEN_ENV_VAR).is_ok_and(|value| value == "1");
+        self.assert_json_matches_file_inner(path.as_ref(), is_updating);
     }
 
-    /// Assert that the status code is **within** the 2xx range.
-    /// i.e. The range from 200-299.
     #[track_caller]
-    pub fn assert_status_success(&self) {
-        let status_code = self.status_code.as_u16();
-        let received_debug = StatusCodeFormatter(self.status_code);
+    fn assert_json_matches_file_inner(&self, path: &Path, is_updating: bool) {
         let request_format = &self.request_format;
+        let received = self.json_value();
 
-        assert!(
-            200 <= status_code && status_code <= 299,
-            "Expect status code within 2xx range, got {received_debug}, for request {request_format}"
-        );
-    }
+        if is_updating {
+            let mut received_text = serde_json::to_string_pretty(&received)
+                .with_context(|| {
+                    format!("Serializing response to Json, for request {request_format}")
+                })
+                .unwrap();
+            received_text.push('\n');
 
-    /// Assert that the status code is **outside** the 2xx range.
-    /// i.e. A status code less than 200, or 300 or more.
+            fs::write(path, received_text)
+                .with_context(|| {
+                    format!("Writing golden file {path:?}, for request {request_format}")
+                })
+                .unwrap();
+
+            return;
+        }
+
+        let expected_bytes = fs::read(path)
+            .with_context(|| format!("Reading golden file {path:?}, for request {request_format}"))
+            .unwrap();
+        let expected = serde_json::from_slice::<Value>(&expected_bytes)
+            .with_context(|| {
+                format!("Parsing Json from golden file {path:?}, for request {request_format}")
+            })
+            .unwrap();
+
+        if let Some(mismatch) = find_json_mismatch(&expected, received, |a, b| a == b) {
+            panic!("Expected Json to match golden file {path:?}, {mismatch}, for request {request_format}");
+        }
+    }
+
+    /// Deserializes the contents of the request as Json,
+    /// and asserts it is a subset of the value given.
+    ///
+    /// Every key in the response must also be in the `superset`, with the same value.
+    /// The `superset` may have extra keys which are not in the response.
+    /// This is useful for catching new fields being returned by accident.
+    ///
+    /// If the response has anything not in the `superset`, or is not Json,
+    /// then this will panic, listing everything which does not match.
     #[track_caller]
-    pub fn assert_status_failure(&self) {
-        let status_code = self.status_code.as_u16();
-        let received_debug = StatusCodeFormatter(self.status_code);
+    pub fn assert_json_subset_of(&self, superset: &Value) {
+        let received = self.json_value();
+        let mismatches = find_json_subset_mismatches(received, superset);
+
+        if !mismatches.is_empty() {
+            let request_format = &self.request_format;
+            let mismatches_str = mismatches
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("; ");
+
+            panic!("Expected Json to be a subset, {mismatches_str}, for request {request_format}");
+        }
+    }
+
+    /// Deserializes the contents of the request as Json,
+    /// and asserts it matches the value given, ignoring the paths listed.
+    ///
+    /// This is useful for comparing against a typed value,
+    /// when some fields are generated, such as an `id` or `created_at`.
+    ///
+    /// Paths use the [Json path](crate::TestResponse#json-paths) format,
+    /// such as `"pets[0].id"`. The paths are removed from both the expected value,
+    /// and the response, before comparing.
+    ///
+    /// If `expected` does not match, or the response is not Json,
+    /// then this will panic, reporting where in the Json the mismatch is.
+    #[track_caller]
+    pub fn assert_json_ignoring<T>(&self, expected: &T, ignore_paths: &[&str])
+    where
+        T: Serialize,
+    {
         let request_format = &self.request_format;
+        let mut expected_value = serde_json::to_value(expected)
+            .with_context(|| {
+                format!("Serializing expected value to Json, for request {request_format}")
+            })
+            .unwrap();
+        let mut received_value = self.json_value().clone();
 
-        assert!(
-            status_code < 200 || 299 < status_code,
-            "Expect status code outside 2xx range, got {received_debug}, for request {request_format}",
-        );
+        for ignore_path in ignore_paths {
+            remove_json_path(&mut expected_value, ignore_path);
+            remove_json_path(&mut received_value, ignore_path);
+        }
+
+        let maybe_mismatch = find_json_mismatch(&expected_value, &received_value, |a, b| a == b);
+        if let Some(mismatch) = maybe_mismatch {
+            panic!("Expected Json to match, {mismatch}, for request {request_format}");
+        }
     }
 
-    /// Assert the response status code is 400.
+    /// Asserts the response is a Json array, with the number of elements given.
+    ///
+    /// This will panic if the response is not Json, is not an array,
+    /// or has a different number of elements.
     #[track_caller]
-    pub fn assert_status_bad_request(&self) {
-        self.assert_status(StatusCode::BAD_REQUEST)
+    pub fn assert_json_array_len(&self, expected: usize) {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+        let Value::Array(items) = received else {
+            panic!("Expected Json array of length {expected}, received {received}, for request {request_format}");
+        };
+
+        let received_len = items.len();
+        if received_len != expected {
+            panic!("Expected Json array of length {expected}, received length {received_len}, for request {request_format}");
+        }
     }
 
-    /// Assert the response status code is 404.
+    /// Asserts the response is a Json object, with the number of keys given.
+    ///
+    /// This will panic if the response is not Json, is not an object,
+    /// or has a different number of keys.
     #[track_caller]
-    pub fn assert_status_not_found(&self) {
-        self.assert_status(StatusCode::NOT_FOUND)
+    pub fn assert_json_object_len(&self, expected: usize) {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+        let Value::Object(map) = received else {
+            panic!("Expected Json object with {expected} keys, received {received}, for request {request_format}");
+        };
+
+        let received_len = map.len();
+        if received_len != expected {
+            panic!("Expected Json object with {expected} keys, received {received_len} keys, for request {request_format}");
+        }
     }
 
-    /// Assert the response status code is 401.
+    /// Asserts the response is a Json object, which contains all of the keys given.
+    /// The values of the keys are not checked.
+    ///
+    /// Nested keys can be checked using a [Json path](crate::TestResponse#json-paths),
+    /// such as `"user.email"` or `"pets[0].name"`.
+    ///
+    /// This will panic if the response is not a Json object,
+    /// or if any of the keys are missing, listing all of the missing keys.
     #[track_caller]
-    pub fn assert_status_unauthorized(&self) {
-        self.assert_status(StatusCode::UNAUTHORIZED)
+    pub fn assert_json_has_keys(&self, keys: &[&str]) {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+        if !received.is_object() {
+            panic!("Expected Json object, received {received}, for request {request_format}");
+        }
+
+        let missing_keys: Vec<&str> = keys
+            .iter()
+            .copied()
+            .filter(|key| get_json_path(received, key).is_none())
+            .collect();
+
+        if !missing_keys.is_empty() {
+            let missing_keys_str = missing_keys.join("', '");
+            panic!("Expected Json to contain keys, missing '{missing_keys_str}', for request {request_format}");
+        }
     }
 
-    /// Assert the response status code is 403.
+    /// Asserts the value at the Json path given matches the value provided.
+    ///
+    /// Paths use the [Json path](crate::TestResponse#json-paths) format,
+    /// such as `"users[0].name"`.
+    ///
+    /// This will panic if the path is missing, or if the value does not match.
     #[track_caller]
-    pub fn assert_status_forbidden(&self) {
-        self.assert_status(StatusCode::FORBIDDEN)
+    pub fn assert_json_path<T>(&self, path: &str, expected: &T)
+    where
+        T: ?Sized + Serialize,
+    {
+        let request_format = &self.request_format;
+        let expected_value = serde_json::to_value(expected)
+            .with_context(|| {
+                format!("Failed to serialize expected value for Json path '{path}', for request {request_format}")
+            })
+            .unwrap();
+        let received = self.json_value();
+
+        match get_json_path(received, path) {
+            Some(value) if *value == expected_value => {}
+            Some(value) => {
+                panic!("Expected Json path '{path}' to be {expected_value}, found {value}, for request {request_format}");
+            }
+            None => {
+                panic!("Expected Json path '{path}' to be {expected_value}, it is missing, for request {request_format}");
+            }
+        }
     }
 
-    /// Assert the response status code is 200.
+    /// Asserts the value at the Json path given is a number, equal to the one provided.
+    ///
+    /// Both integers and floats in the response are compared as an `f64`,
+    /// so `20` and `20.0` are treated as the same.
+    /// Use [`TestResponse::assert_json_int_at()`](crate::TestResponse::assert_json_int_at())
+    /// to require an integer.
+    ///
+    /// This will panic if the path is missing, is not a number, or does not match.
     #[track_caller]
-    pub fn assert_status_ok(&self) {
-        self.assert_status(StatusCode::OK)
+    pub fn assert_json_number_at(&self, path: &str, expected: f64) {
+        let request_format = &self.request_format;
+        let value = self.json_number_at(path);
+        let received = value
+            .as_f64()
+            .with_context(|| {
+                format!("Expected Json path '{path}' to be a number, found {value}, for request {request_format}")
+            })
+            .unwrap();
+
+        if received != expected {
+            panic!("Expected Json path '{path}' to be {expected}, found {value}, for request {request_format}");
+        }
     }
 
-    /// Assert the response status code is **not** 200.
+    /// Asserts the value at the Json path given is an integer, equal to the one provided.
+    ///
+    /// Floats in the response, such as `20.0`, are not treated as integers.
+    ///
+    /// This will panic if the path is missing, is not an integer, or does not match.
     #[track_caller]
-    pub fn assert_status_not_ok(&self) {
-        self.assert_not_status(StatusCode::OK)
+    pub fn assert_json_int_at(&self, path: &str, expected: i64) {
+        let request_format = &self.request_format;
+        let value = self.json_number_at(path);
+        let received = value
+            .as_i64()
+            .with_context(|| {
+                format!("Expected Json path '{path}' to be an integer, found {value}, for request {request_format}")
+            })
+            .unwrap();
+
+        if received != expected {
+            panic!("Expected Json path '{path}' to be {expected}, found {value}, for request {request_format}");
+        }
     }
 
-    /// Assert the response status code matches the one given.
     #[track_caller]
-    pub fn assert_status(&self, expected_status_code: StatusCode) {
-        let status_code = self.status_code.as_u16();
-        let received_debug = StatusCodeFormatter(self.status_code);
-        let expected_debug = StatusCodeFormatter(expected_status_code);
+    fn json_number_at(&self, path: &str) -> Value {
         let request_format = &self.request_format;
+        let received = self.json_value();
 
-        assert_eq!(
-            expected_status_code, status_code,
-            "Expected status code {expected_debug}, got {received_debug}, for request {request_format}",
-        );
+        get_json_path(received, path)
+            .cloned()
+            .with_context(|| {
+                format!("Expected Json path '{path}' to be a number, it is missing, for request {request_format}")
+            })
+            .unwrap()
+    }
+
+    /// Asserts the value at the Json path given passes the predicate provided.
+    /// This is for checks which are more flexible than equality,
+    /// such as a number being within a range.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// use ::axum::Json;
+    /// use ::axum::Router;
+    /// use ::axum::routing::get;
+    /// use ::axum_test::TestServer;
+    /// use ::serde_json::json;
+    ///
+    /// let app = Router::new()
+    ///     .route(&"/user", get(|| async { Json(json!({ "age": 20 })) }));
+    /// let server = TestServer::new(app)?;
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json_at("age", |age| age.as_u64().is_some_and(|age| age > 0));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// This will panic if the path is missing, or if the predicate returns false.
+    #[track_caller]
+    pub fn assert_json_at<F>(&self, path: &str, predicate: F)
+    where
+        F: FnOnce(&Value) -> bool,
+    {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+
+        match get_json_path(received, path) {
+            Some(value) if predicate(value) => {}
+            Some(value) => {
+                panic!("Expected Json path '{path}' to pass the predicate given, found {value}, for request {request_format}");
+            }
+            None => {
+                panic!("Expected Json path '{path}' to pass the predicate given, it is missing, for request {request_format}");
+            }
+        }
+    }
+
+    /// Asserts the response is a Json error, with the error code given.
+    ///
+    /// This expects errors in the envelope `{ "error": { "code": ..., "message": ... } }`,
+    /// and checks the value at `error.code`.
+    /// For other envelopes use [`TestResponse::assert_json_path()`](crate::TestResponse::assert_json_path()).
+    ///
+    /// On a mismatch this will panic, including the full response body.
+    #[track_caller]
+    pub fn assert_json_error(&self, expected_code: &str) {
+        let received = self.json_value();
+
+        match get_json_path(received, JSON_ERROR_CODE_PATH) {
+            Some(Value::String(code)) if code == expected_code => {}
+            maybe_code => {
+                let found = maybe_code
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| "nothing".to_string());
+                let request_format = &self.request_format;
+
+                panic!("Expected Json error code '{expected_code}', found {found} at '{JSON_ERROR_CODE_PATH}', with body {received}, for request {request_format}");
+            }
+        }
+    }
+
+    /// Asserts the response is a Json array, where the value at the path given
+    /// is unique across every item. This is useful for checking list endpoints,
+    /// such as ensuring pagination never returns the same item twice.
+    ///
+    /// The path is relative to each item, such as `"id"` or `"user.id"`.
+    ///
+    /// This will panic if an item is missing the path,
+    /// or if two items have the same value.
+    #[track_caller]
+    pub fn assert_json_array_unique_by(&self, path: &str) {
+        let values = self.json_array_values_at(path);
+        let mut seen = HashMap::<String, usize>::new();
+
+        for (index, value) in values.iter().enumerate() {
+            if let Some(first_index) = seen.insert(value.to_string(), index) {
+                let request_format = &self.request_format;
+                panic!("Expected Json array items to be unique by '{path}', found {value} at index {first_index} and {index}, for request {request_format}");
+            }
+        }
+    }
+
+    /// Asserts the response is a Json array, where the value at the path given
+    /// is sorted across every item. Equal values next to each other are allowed,
+    /// use [`TestResponse::assert_json_array_unique_by()`](crate::TestResponse::assert_json_array_unique_by())
+    /// as well for strict ordering.
+    ///
+    /// The path is relative to each item, such as `"id"` or `"user.id"`.
+    /// Numbers and strings can be compared, and all values must be of the same type.
+    ///
+    /// This will panic if an item is missing the path, or if the items are out of order.
+    #[track_caller]
+    pub fn assert_json_array_sorted_by(&self, path: &str, ascending: bool) {
+        let values = self.json_array_values_at(path);
+        let request_format = &self.request_format;
+        let direction = if ascending { "ascending" } else { "descending" };
+
+        for (index, pair) in values.windows(2).enumerate() {
+            let (previous, current) = (&pair[0], &pair[1]);
+            let ordering = compare_json_values(previous, current)
+                .with_context(|| {
+                    format!("Cannot compare Json values {previous} and {current} at '{path}', for request {request_format}")
+                })
+                .unwrap();
+
+            let is_in_order = match ascending {
+                true => ordering != Ordering::Greater,
+                false => ordering != Ordering::Less,
+            };
+            if !is_in_order {
+                let next_index = index + 1;
+                panic!("Expected Json array items to be sorted {direction} by '{path}', found {previous} at index {index} followed by {current} at index {next_index}, for request {request_format}");
+            }
+        }
+    }
+
+    #[track_caller]
+    fn json_array_values_at(&self, path: &str) -> Vec<Value> {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+        let Value::Array(items) = received else {
+            panic!("Expected Json array, received {received}, for request {request_format}");
+        };
+
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                get_json_path(item, path)
+                    .cloned()
+                    .with_context(|| {
+                        format!("Expected Json path '{path}' in array item at index {index}, it is missing, for request {request_format}")
+                    })
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    /// Asserts nothing exists at the Json path given.
+    /// This is useful for ensuring sensitive fields, such as a password,
+    /// are never returned.
+    ///
+    /// Paths use the [Json path](crate::TestResponse#json-paths) format,
+    /// such as `"users[0].password"`.
+    ///
+    /// An explicit `null` counts as being present, and this will panic.
+    /// Use [`TestResponse::assert_json_path_absent_or_null()`](crate::TestResponse::assert_json_path_absent_or_null())
+    /// to allow `null` values.
+    #[track_caller]
+    pub fn assert_json_path_absent(&self, path: &str) {
+        self.assert_json_path_absent_inner(path, false);
+    }
+
+    /// Asserts nothing exists at the Json path given, or it is `null`.
+    ///
+    /// This is the same as [`TestResponse::assert_json_path_absent()`](crate::TestResponse::assert_json_path_absent()),
+    /// but allows the value to be an explicit `null`.
+    #[track_caller]
+    pub fn assert_json_path_absent_or_null(&self, path: &str) {
+        self.assert_json_path_absent_inner(path, true);
+    }
+
+    #[track_caller]
+    fn assert_json_path_absent_inner(&self, path: &str, is_null_allowed: bool) {
+        let received = self.json_value();
+
+        match get_json_path(received, path) {
+            None => {}
+            Some(Value::Null) if is_null_allowed => {}
+            Some(value) => {
+                let request_format = &self.request_format;
+                panic!("Expected Json path '{path}' to be absent, found {value}, for request {request_format}");
+            }
+        }
+    }
+
+    /// Asserts the value at the Json path given is an explicit `null`.
+    ///
+    /// This will panic if the value is not `null`, or if the path is missing.
+    /// Use [`TestResponse::assert_json_path_absent()`](crate::TestResponse::assert_json_path_absent())
+    /// to check for missing paths.
+    #[track_caller]
+    pub fn assert_json_null_at(&self, path: &str) {
+        let request_format = &self.request_format;
+
+        match self.json_value_at(path) {
+            Value::Null => {}
+            value => panic!(
+                "Expected Json path '{path}' to be null, found {value}, for request {request_format}"
+            ),
+        }
+    }
+
+    /// Asserts the value at the Json path given is present, and is not `null`.
+    ///
+    /// This will panic if the value is `null`, or if the path is missing.
+    #[track_caller]
+    pub fn assert_json_not_null_at(&self, path: &str) {
+        let request_format = &self.request_format;
+
+        if self.json_value_at(path).is_null() {
+            panic!("Expected Json path '{path}' to not be null, found null, for request {request_format}");
+        }
+    }
+
+    /// Asserts the response body is exactly an empty Json object, `{}`.
+    ///
+    /// This will panic if the body is anything else, including an empty array.
+    #[track_caller]
+    pub fn assert_json_empty_object(&self) {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+
+        if !received.as_object().is_some_and(|object| object.is_empty()) {
+            panic!("Expected Json to be an empty object, found {received}, for request {request_format}");
+        }
+    }
+
+    /// Asserts the response body is exactly an empty Json array, `[]`.
+    ///
+    /// This will panic if the body is anything else, including an empty object.
+    #[track_caller]
+    pub fn assert_json_empty_array(&self) {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+
+        if !received.as_array().is_some_and(|array| array.is_empty()) {
+            panic!("Expected Json to be an empty array, found {received}, for request {request_format}");
+        }
+    }
+
+    #[track_caller]
+    fn json_value_at(&self, path: &str) -> Value {
+        let request_format = &self.request_format;
+        let received = self.json_value();
+
+        match get_json_path(received, path) {
+            Some(value) => value.clone(),
+            None => panic!(
+                "Expected Json path '{path}' to be present, it is missing, for request {request_format}"
+            ),
+        }
+    }
+
+    /// Deserializes the contents of the request as Yaml,
+    /// and asserts it matches the value given.
+    ///
+    /// If `other` does not match, or the response is not Yaml,
+    /// then this will panic.
+    #[cfg(feature = "yaml")]
+    #[track_caller]
+    pub fn assert_yaml<T>(&self, other: &T)
+    where
+        T: DeserializeOwned + PartialEq<T> + Debug,
+    {
+        assert_eq!(*other, self.yaml::<T>());
+    }
+
+    /// Deserializes the contents of the request as an url encoded form,
+    /// and asserts it matches the value given.
+    ///
+    /// If `other` does not match, or the response cannot be deserialized,
+    /// then this will panic.
+    #[track_caller]
+    pub fn assert_form<T>(&self, other: &T)
+    where
+        T: DeserializeOwned + PartialEq<T> + Debug,
+    {
+        assert_eq!(*other, self.form::<T>());
+    }
+
+    /// Asserts the response body is well-formed Json,
+    /// without deserializing it into any particular type.
+    ///
+    /// If the body cannot be parsed as Json, then this will panic.
+    #[track_caller]
+    pub fn assert_is_json(&self) {
+        if let Err(err) = serde_json::from_slice::<Value>(self.body_without_bom()) {
+            let request_format = &self.request_format;
+            panic!("Expected response to be valid Json, {err}, for request {request_format}");
+        }
+    }
+
+    /// Asserts the response body is Json which survives being parsed,
+    /// and serialized again, without changing.
+    ///
+    /// This catches Json which is changed when read by other Json libraries,
+    /// such as numbers too large to hold without losing digits, or duplicate keys.
+    /// Differences in whitespace, key order, escapes, and how numbers are written are ignored.
+    ///
+    /// If any step fails, or the serialized Json differs from the response, then this will panic.
+    #[track_caller]
+    pub fn assert_json_roundtrips(&self) {
+        let request_format = &self.request_format;
+        let body = self.body_without_bom();
+        let value = serde_json::from_slice::<Value>(body).unwrap_or_else(|err| {
+            panic!("Expected Json to roundtrip, failed to parse response, {err}, for request {request_format}")
+        });
+
+        let serialized = serde_json::to_string(&value).unwrap_or_else(|err| {
+            panic!("Expected Json to roundtrip, failed to serialize, {err}, for request {request_format}")
+        });
+
+        let received = canonical_json(body).unwrap_or_else(|err| {
+            panic!("Expected Json to roundtrip, failed to parse response, {err}, for request {request_format}")
+        });
+        let roundtripped = canonical_json(serialized.as_bytes()).unwrap_or_else(|err| {
+            panic!("Expected Json to roundtrip, failed to parse serialized Json, {err}, for request {request_format}")
+        });
+
+        if received != roundtripped {
+            panic!("Expected Json to roundtrip, it was serialized as {serialized}, for request {request_format}");
+        }
+    }
+
+    /// Asserts the response body is well-formed Yaml,
+    /// without deserializing it into any particular type.
+    ///
+    /// If the body cannot be parsed as Yaml, then this will panic.
+    #[cfg(feature = "yaml")]
+    #[track_caller]
+    pub fn assert_is_yaml(&self) {
+        if let Err(err) = serde_yaml::from_slice::<serde_yaml::Value>(self.body_without_bom()) {
+            let request_format = &self.request_format;
+            panic!("Expected response to be valid Yaml, {err}, for request {request_format}");
+        }
+    }
+
+    /// Asserts the response body is a well-formed url encoded form,
+    /// without deserializing it into any particular type.
+    ///
+    /// This requires the body to be UTF-8, and every field to be a `name=value` pair.
+    /// An empty body is treated as an empty form.
+    ///
+    /// If the body is not a valid form, then this will panic.
+    #[track_caller]
+    pub fn assert_is_form(&self) {
+        let request_format = &self.request_format;
+        let body = ::std::str::from_utf8(self.body_without_bom()).unwrap_or_else(|err| {
+            panic!("Expected response to be a valid form, {err}, for request {request_format}")
+        });
+
+        let maybe_invalid_field = body
+            .split('&')
+            .filter(|field| !field.is_empty())
+            .find(|field| !matches!(field.split_once('='), Some((name, _)) if !name.is_empty()));
+
+        if let Some(invalid_field) = maybe_invalid_field {
+            panic!("Expected response to be a valid form, found field '{invalid_field}' which is not a name=value pair, for request {request_format}");
+        }
+    }
+
+    /// Asserts the header named is present in the response.
+    /// The value of the header is not checked.
+    ///
+    /// If the header is not found, then this will panic.
+    #[track_caller]
+    pub fn assert_header_present<N>(&self, header_name: N)
+    where
+        N: AsHeaderName + Display + Clone,
+    {
+        self.mark_inspected();
+        let debug_header = header_name.clone();
+        let request_format = &self.request_format;
+
+        assert!(
+            self.headers.contains_key(header_name),
+            "Expected header '{debug_header}' to be present, it is missing, for request {request_format}"
+        );
+    }
+
+    /// Asserts the header named is **not** present in the response.
+    ///
+    /// If the header is found, then this will panic.
+    #[track_caller]
+    pub fn assert_header_missing<N>(&self, header_name: N)
+    where
+        N: AsHeaderName + Display + Clone,
+    {
+        self.mark_inspected();
+        let debug_header = header_name.clone();
+        let request_format = &self.request_format;
+
+        assert!(
+            !self.headers.contains_key(header_name),
+            "Expected header '{debug_header}' to be missing, it is present, for request {request_format}"
+        );
+    }
+
+    /// Asserts the header named appears exactly once in the response.
+    ///
+    /// If the header is missing, or is sent more than once, then this will panic.
+    /// When sent more than once, all of the values received are listed.
+    #[track_caller]
+    pub fn assert_header_single<N>(&self, header_name: N)
+    where
+        N: AsHeaderName + Display + Clone,
+    {
+        self.mark_inspected();
+        let debug_header = header_name.clone();
+        let request_format = &self.request_format;
+        let values: Vec<&HeaderValue> = self.headers.get_all(header_name).iter().collect();
+
+        assert!(
+            !values.is_empty(),
+            "Expected header '{debug_header}' to appear once, it is missing, for request {request_format}"
+        );
+        assert!(
+            values.len() == 1,
+            "Expected header '{debug_header}' to appear once, received {} values {values:?}, for request {request_format}",
+            values.len()
+        );
+    }
+
+    /// Asserts no header appears more than once in the response,
+    /// except for those in the list of names given.
+    ///
+    /// This is useful for catching headers emitted twice by mistake,
+    /// whilst allowing headers which are expected to repeat, such as `Set-Cookie`.
+    /// The allowed names are compared ignoring case.
+    #[track_caller]
+    pub fn assert_no_duplicate_headers(&self, allowed_multi: &[&str]) {
+        self.mark_inspected();
+        let request_format = &self.request_format;
+        let duplicates: Vec<String> = self
+            .headers
+            .keys()
+            .filter(|name| {
+                !allowed_multi
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(name.as_str()))
+            })
+            .filter_map(|name| {
+                let values: Vec<&HeaderValue> = self.headers.get_all(name).iter().collect();
+                (values.len() > 1).then(|| format!("'{name}' {values:?}"))
+            })
+            .collect();
+
+        assert!(
+            duplicates.is_empty(),
+            "Expected no duplicate headers, received {}, for request {request_format}",
+            duplicates.join(", ")
+        );
+    }
+
+    /// Asserts the response has a `Content-Type` header,
+    /// if it has a non-empty body.
+    ///
+    /// Responses with an empty body are allowed to have no `Content-Type`.
+    #[track_caller]
+    pub fn assert_has_content_type(&self) {
+        unwrap_assertion(self.check_has_content_type())
+    }
+
+    /// Asserts the first header with the name given matches the regex.
+    ///
+    /// This is useful for headers with values which change, but follow a pattern.
+    /// Such as a request id which is always a UUID.
+    ///
+    /// If the header is missing, or does not match, then this will panic.
+    #[cfg(feature = "regex")]
+    #[track_caller]
+    pub fn assert_header_matches<N>(&self, header_name: N, pattern: &Regex)
+    where
+        N: AsHeaderName + Display + Clone,
+    {
+        self.mark_inspected();
+        let debug_header = header_name.clone();
+        let request_format = &self.request_format;
+        let header_value = self
+            .headers
+            .get(header_name)
+            .map(|header| String::from_utf8_lossy(header.as_bytes()).to_string())
+            .unwrap_or_else(|| {
+                panic!("Expected header '{debug_header}' to be present, it is missing, for request {request_format}")
+            });
+
+        assert!(
+            pattern.is_match(&header_value),
+            "Expected header '{debug_header}' to match '{pattern}', received '{header_value}', for request {request_format}"
+        );
+    }
+
+    /// Asserts the `Cache-Control` header contains the `no-store` directive.
+    ///
+    /// If the header is missing, or the directive is not present, then this will panic.
+    #[track_caller]
+    pub fn assert_cache_control_no_store(&self) {
+        self.assert_cache_control_directive("no-store", None);
+    }
+
+    /// Asserts the `Cache-Control` header contains the `private` directive.
+    ///
+    /// If the header is missing, or the directive is not present, then this will panic.
+    #[track_caller]
+    pub fn assert_cache_control_private(&self) {
+        self.assert_cache_control_directive("private", None);
+    }
+
+    /// Asserts the `Cache-Control` header contains a `max-age` directive,
+    /// set to the number of seconds given.
+    ///
+    /// If the header is missing, or `max-age` is missing or different, then this will panic.
+    #[track_caller]
+    pub fn assert_cache_control_max_age(&self, secs: u64) {
+        self.assert_cache_control_directive("max-age", Some(secs));
+    }
+
+    #[track_caller]
+    fn assert_cache_control_directive(&self, name: &str, expected_value: Option<u64>) {
+        self.mark_inspected();
+        let request_format = &self.request_format;
+        let header_values = self
+            .headers
+            .get_all(CACHE_CONTROL)
+            .iter()
+            .map(|header| {
+                String::from_utf8_lossy(header.as_bytes())
+                    .trim()
+                    .to_string()
+            })
+            .collect::<Vec<String>>();
+        if header_values.is_empty() {
+            panic!("Expected header 'cache-control' to be present, it is missing, for request {request_format}");
+        }
+
+        let header_value = header_values.join(", ");
+        let directives = parse_cache_control(&header_value);
+        let maybe_directive = directives
+            .iter()
+            .find(|(directive_name, _)| directive_name == name);
+
+        let is_match = match (maybe_directive, expected_value) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some((_, value)), Some(expected_value)) => {
+                value.as_deref().and_then(|value| value.parse::<u64>().ok()) == Some(expected_value)
+            }
+        };
+
+        if !is_match {
+            let expected_directive = match expected_value {
+                None => name.to_string(),
+                Some(expected_value) => format!("{name}={expected_value}"),
+            };
+
+            panic!("Expected 'cache-control' to contain '{expected_directive}', received '{header_value}', for request {request_format}");
+        }
+    }
+
+    /// Asserts the response did not set any cookies,
+    /// i.e. there are no `Set-Cookie` headers.
+    ///
+    /// If any cookies are found, then this will panic listing them.
+    #[track_caller]
+    pub fn assert_no_cookies(&self) {
+        let cookies = self
+            .iter_cookies()
+            .map(|cookie| cookie.to_string())
+            .collect::<Vec<String>>();
+
+        if !cookies.is_empty() {
+            let request_format = &self.request_format;
+            let cookies_str = cookies.join("', '");
+            panic!("Expected no cookies to be set, found '{cookies_str}', for request {request_format}");
+        }
+    }
+
+    /// Assert that the status code is **within** the 2xx range.
+    /// i.e. The range from 200-299.
+    #[track_caller]
+    pub fn assert_status_success(&self) {
+        unwrap_assertion(self.check_status_success())
+    }
+
+    /// Assert that the status code is **outside** the 2xx range.
+    /// i.e. A status code less than 200, or 300 or more.
+    #[track_caller]
+    pub fn assert_status_failure(&self) {
+        unwrap_assertion(self.check_status_failure())
+    }
+
+    /// Assert the response status code is 400.
+    #[track_caller]
+    pub fn assert_status_bad_request(&self) {
+        self.assert_status(StatusCode::BAD_REQUEST)
+    }
+
+    /// Assert the response status code is 404.
+    #[track_caller]
+    pub fn assert_status_not_found(&self) {
+        self.assert_status(StatusCode::NOT_FOUND)
+    }
+
+    /// Assert the response status code is 401.
+    #[track_caller]
+    pub fn assert_status_unauthorized(&self) {
+        self.assert_status(StatusCode::UNAUTHORIZED)
+    }
+
+    /// Assert the response status code is 403.
+    #[track_caller]
+    pub fn assert_status_forbidden(&self) {
+        self.assert_status(StatusCode::FORBIDDEN)
+    }
+
+    /// Assert the response status code is 206,
+    /// and it has a valid `Content-Range` header.
+    #[track_caller]
+    pub fn assert_partial_content(&self) {
+        self.assert_status(StatusCode::PARTIAL_CONTENT);
+
+        if self.content_range().is_none() {
+            let request_format = &self.request_format;
+            let received = self.maybe_header(CONTENT_RANGE);
+            panic!("Expected a valid 'Content-Range' header for partial content, received {received:?}, for request {request_format}");
+        }
+    }
+
+    /// Assert the response status code is 200.
+    #[track_caller]
+    pub fn assert_status_ok(&self) {
+        self.assert_status(StatusCode::OK)
+    }
+
+    /// Assert the response status code is **not** 200.
+    #[track_caller]
+    pub fn assert_status_not_ok(&self) {
+        self.assert_not_status(StatusCode::OK)
+    }
+
+    /// Assert the response status code matches the one given.
+    #[track_caller]
+    pub fn assert_status(&self, expected_status_code: StatusCode) {
+        self.mark_inspected();
+        let status_code = self.status_code.as_u16();
+        let received_debug = StatusCodeFormatter(self.status_code);
+        let expected_debug = StatusCodeFormatter(expected_status_code);
+        let request_format = &self.request_format;
+
+        assert_eq!(
+            expected_status_code, status_code,
+            "Expected status code {expected_debug}, got {received_debug}, for request {request_format}",
+        );
+    }
+
+    /// Assert the response status code matches the one given,
+    /// including the message provided on failure.
+    ///
+    /// This is useful for telling apart the same assertion used in several places.
+    #[track_caller]
+    pub fn assert_status_msg(&self, expected_status_code: StatusCode, message: &str) {
+        if let Err(err) = self.check_status(expected_status_code) {
+            panic!("{message}: {err}");
+        }
+    }
+
+    /// Assert the response status code is one of those given.
+    ///
+    /// This is useful for endpoints which can legitimately return different statuses,
+    /// such as a `200` or a `204`.
+    #[track_caller]
+    pub fn assert_status_one_of(&self, allowed_status_codes: &[StatusCode]) {
+        unwrap_assertion(self.check_status_one_of(allowed_status_codes))
+    }
+
+    /// Assert the response status code does **not** match the one given.
+    #[track_caller]
+    pub fn assert_not_status(&self, expected_status_code: StatusCode) {
+        self.mark_inspected();
+        let expected_debug = StatusCodeFormatter(expected_status_code);
+        let request_format = &self.request_format;
+
+        assert_ne!(
+            expected_status_code, self.status_code,
+            "Expected status code to not be {expected_debug}, it is, for request {request_format}",
+        );
+    }
+
+    /// Checks the status code is **within** the 2xx range.
+    ///
+    /// This is the same as [`TestResponse::assert_status_success()`](crate::TestResponse::assert_status_success()),
+    /// but returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    #[allow(clippy::manual_range_contains)]
+    pub fn check_status_success(&self) -> Result<(), AssertionError> {
+        self.mark_inspected();
+        let status_code = self.status_code.as_u16();
+        if 200 <= status_code && status_code <= 299 {
+            return Ok(());
+        }
+
+        let received_debug = StatusCodeFormatter(self.status_code);
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expect status code within 2xx range, got {received_debug}, for request {request_format}"
+        )))
+    }
+
+    /// Checks the status code is **outside** the 2xx range.
+    ///
+    /// This is the same as [`TestResponse::assert_status_failure()`](crate::TestResponse::assert_status_failure()),
+    /// but returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    #[allow(clippy::manual_range_contains)]
+    pub fn check_status_failure(&self) -> Result<(), AssertionError> {
+        self.mark_inspected();
+        let status_code = self.status_code.as_u16();
+        if status_code < 200 || 299 < status_code {
+            return Ok(());
+        }
+
+        let received_debug = StatusCodeFormatter(self.status_code);
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expect status code outside 2xx range, got {received_debug}, for request {request_format}"
+        )))
+    }
+
+    /// Checks the response status code is 200.
+    ///
+    /// This returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_status_ok(&self) -> Result<(), AssertionError> {
+        self.check_status(StatusCode::OK)
+    }
+
+    /// Checks the response status code is **not** 200.
+    ///
+    /// This returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_status_not_ok(&self) -> Result<(), AssertionError> {
+        self.check_not_status(StatusCode::OK)
+    }
+
+    /// Checks the response status code matches the one given.
+    ///
+    /// This is the same as [`TestResponse::assert_status()`](crate::TestResponse::assert_status()),
+    /// but returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_status(&self, expected_status_code: StatusCode) -> Result<(), AssertionError> {
+        self.mark_inspected();
+        if self.status_code == expected_status_code {
+            return Ok(());
+        }
+
+        let received_debug = StatusCodeFormatter(self.status_code);
+        let expected_debug = StatusCodeFormatter(expected_status_code);
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expected status code {expected_debug}, got {received_debug}, for request {request_format}"
+        )))
+    }
+
+    /// Checks the response status code is one of those given.
+    ///
+    /// This is the same as [`TestResponse::assert_status_one_of()`](crate::TestResponse::assert_status_one_of()),
+    /// but returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_status_one_of(
+        &self,
+        allowed_status_codes: &[StatusCode],
+    ) -> Result<(), AssertionError> {
+        self.mark_inspected();
+        if allowed_status_codes.contains(&self.status_code) {
+            return Ok(());
+        }
+
+        let allowed_debug = allowed_status_codes
+            .iter()
+            .map(|status_code| StatusCodeFormatter(*status_code).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        let received_debug = StatusCodeFormatter(self.status_code);
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expected status code to be one of {allowed_debug}, got {received_debug}, for request {request_format}"
+        )))
+    }
+
+    /// Checks the response status code does **not** match the one given.
+    ///
+    /// This is the same as [`TestResponse::assert_not_status()`](crate::TestResponse::assert_not_status()),
+    /// but returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_not_status(&self, expected_status_code: StatusCode) -> Result<(), AssertionError> {
+        self.mark_inspected();
+        if self.status_code != expected_status_code {
+            return Ok(());
+        }
+
+        let expected_debug = StatusCodeFormatter(expected_status_code);
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expected status code to not be {expected_debug}, it is, for request {request_format}"
+        )))
+    }
+
+    /// Checks the response body matches the text given.
+    ///
+    /// This returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_text<C>(&self, expected: C) -> Result<(), AssertionError>
+    where
+        C: AsRef<str>,
+    {
+        let expected = expected.as_ref();
+        let received = self.text();
+        if expected == received {
+            return Ok(());
+        }
+
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expected text {expected:?}, received {received:?}, for request {request_format}"
+        )))
+    }
+
+    /// Deserializes the contents of the request as Json,
+    /// and checks it matches the value given.
+    ///
+    /// This returns an [`AssertionError`](crate::AssertionError) instead of panicking,
+    /// including when the response cannot be deserialized.
+    pub fn check_json<T>(&self, expected: &T) -> Result<(), AssertionError>
+    where
+        T: DeserializeOwned + PartialEq<T> + Debug,
+    {
+        let request_format = &self.request_format;
+        let received = serde_json::from_slice::<T>(self.body_without_bom()).map_err(|err| {
+            AssertionError::new(format!(
+                "Deserializing response from Json failed, {err}, for request {request_format}"
+            ))
+        })?;
+
+        if *expected == received {
+            return Ok(());
+        }
+
+        Err(AssertionError::new(format!(
+            "Expected Json {expected:?}, received {received:?}, for request {request_format}"
+        )))
+    }
+
+    /// Checks the response has a `Content-Type` header, if it has a non-empty body.
+    ///
+    /// This is the same as [`TestResponse::assert_has_content_type()`](crate::TestResponse::assert_has_content_type()),
+    /// but returns an [`AssertionError`](crate::AssertionError) instead of panicking.
+    pub fn check_has_content_type(&self) -> Result<(), AssertionError> {
+        self.mark_inspected();
+        if self.response_body.is_empty() || self.headers.contains_key(CONTENT_TYPE) {
+            return Ok(());
+        }
+
+        let body_len = self.response_body.len();
+        let request_format = &self.request_format;
+        Err(AssertionError::new(format!(
+            "Expected header 'content-type' to be present for a body of {body_len} bytes, it is missing, for request {request_format}"
+        )))
+    }
+}
+
+/// Compares two Json numbers, or two Json strings.
+/// Any other values cannot be compared, and `None` is returned.
+fn compare_json_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+#[track_caller]
+fn unwrap_assertion(result: Result<(), AssertionError>) {
+    if let Err(err) = result {
+        panic!("{err}");
+    }
+}
+
+impl From<TestResponse> for Bytes {
+    fn from(response: TestResponse) -> Self {
+        response.into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test_utf8_bom {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::HeaderMap;
+    use ::http::StatusCode;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    fn new_response_with_bom(body: &str) -> TestResponse {
+        let body_bytes = [b"\xEF\xBB\xBF".as_slice(), body.as_bytes()].concat();
+        TestResponse::from_parts(StatusCode::OK, HeaderMap::new(), Bytes::from(body_bytes))
+    }
+
+    #[test]
+    fn it_should_parse_json_prefixed_with_bom() {
+        let response = new_response_with_bom(r#"{ "name": "Joe" }"#);
+
+        assert_eq!(response.json::<Value>(), json!({ "name": "Joe" }));
+    }
+
+    #[test]
+    fn it_should_remove_bom_from_text() {
+        let response = new_response_with_bom("hello!");
+
+        assert_eq!(response.text(), "hello!");
+        assert_eq!(response.as_bytes().len(), 9);
+    }
+
+    #[test]
+    fn it_should_remove_bom_from_as_str() {
+        let response = new_response_with_bom("hello!");
+
+        assert_eq!(response.as_str(), Ok("hello!"));
+        assert_eq!(response.as_str().unwrap(), response.text());
+    }
+}
+
+#[cfg(test)]
+mod test_status_headers_body {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::header::CONTENT_TYPE;
+    use ::http::HeaderMap;
+    use ::http::HeaderValue;
+    use ::http::StatusCode;
+
+    #[test]
+    fn it_should_return_each_part_of_the_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        let response =
+            TestResponse::from_parts(StatusCode::CREATED, headers, Bytes::from("hello!"));
+
+        let (status_code, headers, body) = response.status_headers_body();
+
+        assert_eq!(status_code, StatusCode::CREATED);
+        assert_eq!(headers.get(CONTENT_TYPE).unwrap(), "text/plain");
+        assert_eq!(body, &Bytes::from("hello!"));
+    }
+}
+
+#[cfg(test)]
+mod test_apply_cookies_to {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::post;
+    use ::axum::Router;
+    use ::axum_extra::extract::cookie::Cookie as AxumCookie;
+    use ::axum_extra::extract::cookie::CookieJar as AxumCookieJar;
+    use ::http::StatusCode;
+
+    async fn route_post_login(cookies: AxumCookieJar) -> (AxumCookieJar, &'static str) {
+        (
+            cookies.add(AxumCookie::new("session", "abc123")),
+            "logged in",
+        )
+    }
+
+    async fn route_get_me(cookies: AxumCookieJar) -> Result<&'static str, StatusCode> {
+        match cookies.get("session").map(|cookie| cookie.value()) {
+            Some("abc123") => Ok("Joe"),
+            _ => Err(StatusCode::UNAUTHORIZED),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_apply_cookies_from_one_server_to_another() {
+        let auth_server = TestServer::new(Router::new().route("/login", post(route_post_login)))
+            .expect("Should create auth server");
+        let mut api_server = TestServer::new(Router::new().route("/me", get(route_get_me)))
+            .expect("Should create api server");
+
+        api_server
+            .get("/me")
+            .expect_failure()
+            .await
+            .assert_status_unauthorized();
+
+        let login_response = auth_server.post("/login").await;
+        login_response.apply_cookies_to(&mut api_server);
+
+        api_server.get("/me").await.assert_text("Joe");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_text_or_empty {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::HeaderMap;
+    use ::http::StatusCode;
+
+    fn new_response(body: &'static str) -> TestResponse {
+        TestResponse::from_parts(
+            StatusCode::OK,
+            HeaderMap::new(),
+            Bytes::from_static(body.as_bytes()),
+        )
+    }
+
+    #[test]
+    fn it_should_pass_for_an_empty_body() {
+        new_response("").assert_text_or_empty("hello!");
+    }
+
+    #[test]
+    fn it_should_pass_for_a_matching_body() {
+        new_response("hello!").assert_text_or_empty("hello!");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected response body to be empty or 'hello!', received 'goodbye!', for request GET <unknown>"
+    )]
+    fn it_should_panic_for_a_different_body() {
+        new_response("goodbye!").assert_text_or_empty("hello!");
+    }
+}
+
+#[cfg(test)]
+mod test_text_lines {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    #[tokio::test]
+    async fn it_should_split_body_into_lines() {
+        let app = Router::new()
+            .route("/csv", get(|| async { "name,age\nJoe,20\r\nJulia,23\n" }))
+            .route("/empty", get(|| async { "" }));
+        let server = TestServer::new(app).unwrap();
+
+        let lines = server.get("/csv").await.text_lines();
+
+        assert_eq!(lines, vec!["name,age", "Joe,20", "Julia,23"]);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_no_lines_for_empty_body() {
+        let app = Router::new()
+            .route("/csv", get(|| async { "name,age\nJoe,20\r\nJulia,23\n" }))
+            .route("/empty", get(|| async { "" }));
+        let server = TestServer::new(app).unwrap();
+
+        let lines = server.get("/empty").await.text_lines();
+
+        assert!(lines.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_assert_line_count_for_matching_count() {
+        let app = Router::new()
+            .route("/csv", get(|| async { "name,age\nJoe,20\r\nJulia,23\n" }))
+            .route("/empty", get(|| async { "" }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/csv").await.assert_line_count(3);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected response body to have 2 lines, received 3 lines, for request GET /csv"
+    )]
+    async fn it_should_panic_assert_line_count_for_different_count() {
+        let app = Router::new()
+            .route("/csv", get(|| async { "name,age\nJoe,20\r\nJulia,23\n" }))
+            .route("/empty", get(|| async { "" }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/csv").await.assert_line_count(2);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_bytes {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::HeaderMap;
+    use ::http::StatusCode;
+
+    fn new_response(body: &'static [u8]) -> TestResponse {
+        TestResponse::from_parts(StatusCode::OK, HeaderMap::new(), Bytes::from_static(body))
+    }
+
+    #[test]
+    fn it_should_pass_for_matching_bytes() {
+        new_response(&[0, 1, 2, 255]).assert_bytes(&[0, 1, 2, 255]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected response body to match the bytes given, first difference at offset 2, expected length 4, received length 4, for request GET <unknown>"
+    )]
+    fn it_should_panic_with_offset_of_first_difference() {
+        new_response(&[0, 1, 2, 255]).assert_bytes(&[0, 1, 3, 255]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected response body to match the bytes given, first difference at offset 3, expected length 3, received length 4, for request GET <unknown>"
+    )]
+    fn it_should_panic_at_end_of_shorter_body_when_lengths_differ() {
+        new_response(&[0, 1, 2, 255]).assert_bytes(&[0, 1, 2]);
+    }
+}
+
+#[cfg(test)]
+mod test_from_parts {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::header::CONTENT_TYPE;
+    use ::http::HeaderMap;
+    use ::http::HeaderValue;
+    use ::http::StatusCode;
+
+    #[test]
+    fn it_should_assert_on_the_parts_given() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+
+        let response = TestResponse::from_parts(StatusCode::OK, headers, Bytes::from("hello!"));
+
+        response.assert_status_ok();
+        response.assert_text("hello!");
+        response.assert_header_present(CONTENT_TYPE);
+    }
+
+    #[test]
+    #[should_panic(expected = "for request GET <unknown>")]
+    fn it_should_use_placeholder_request_in_panics() {
+        let response =
+            TestResponse::from_parts(StatusCode::NOT_FOUND, HeaderMap::new(), Bytes::new());
+
+        response.assert_status_ok();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_success {
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::http::StatusCode;
+
+    use crate::TestServer;
+
+    pub async fn route_get_pass() -> StatusCode {
+        StatusCode::OK
+    }
+
+    pub async fn route_get_fail() -> StatusCode {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_when_200() {
+        let router = Router::new()
+            .route(&"/pass", get(route_get_pass))
+            .route(&"/fail", get(route_get_fail));
+
+        let server = TestServer::new(router).unwrap();
+
+        let response = server.get(&"/pass").await;
+
+        response.assert_status_success()
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_when_not_200() {
+        let router = Router::new()
+            .route(&"/pass", get(route_get_pass))
+            .route(&"/fail", get(route_get_fail));
+
+        let server = TestServer::new(router).unwrap();
+
+        let response = server.get(&"/fail").expect_failure().await;
+
+        response.assert_status_success()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_failure {
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::http::StatusCode;
+
+    use crate::TestServer;
+
+    pub async fn route_get_pass() -> StatusCode {
+        StatusCode::OK
+    }
+
+    pub async fn route_get_fail() -> StatusCode {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_when_not_200() {
+        let router = Router::new()
+            .route(&"/pass", get(route_get_pass))
+            .route(&"/fail", get(route_get_fail));
+
+        let server = TestServer::new(router).unwrap();
+        let response = server.get(&"/fail").expect_failure().await;
+
+        response.assert_status_failure()
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_when_200() {
+        let router = Router::new()
+            .route(&"/pass", get(route_get_pass))
+            .route(&"/fail", get(route_get_fail));
+
+        let server = TestServer::new(router).unwrap();
+        let response = server.get(&"/pass").await;
+
+        response.assert_status_failure()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_status {
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::http::StatusCode;
+
+    use crate::TestServer;
+
+    pub async fn route_get_ok() -> StatusCode {
+        StatusCode::OK
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_if_given_right_status_code() {
+        let router = Router::new().route(&"/ok", get(route_get_ok));
+        let server = TestServer::new(router).unwrap();
+
+        server.get(&"/ok").await.assert_status(StatusCode::OK);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_when_status_code_does_not_match() {
+        let router = Router::new().route(&"/ok", get(route_get_ok));
+        let server = TestServer::new(router).unwrap();
+
+        server.get(&"/ok").await.assert_status(StatusCode::ACCEPTED);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_not_status {
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::http::StatusCode;
+
+    use crate::TestServer;
+
+    pub async fn route_get_ok() -> StatusCode {
+        StatusCode::OK
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_if_status_code_does_not_match() {
+        let router = Router::new().route(&"/ok", get(route_get_ok));
+        let server = TestServer::new(router).unwrap();
+
+        server
+            .get(&"/ok")
+            .await
+            .assert_not_status(StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_status_code_matches() {
+        let router = Router::new().route(&"/ok", get(route_get_ok));
+        let server = TestServer::new(router).unwrap();
+
+        server.get(&"/ok").await.assert_not_status(StatusCode::OK);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_partial_content {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::header::CONTENT_RANGE;
+    use ::http::HeaderMap;
+    use ::http::HeaderValue;
+    use ::http::StatusCode;
+
+    fn new_response(status_code: StatusCode, content_range: Option<&'static str>) -> TestResponse {
+        let mut headers = HeaderMap::new();
+        if let Some(content_range) = content_range {
+            headers.insert(CONTENT_RANGE, HeaderValue::from_static(content_range));
+        }
+
+        TestResponse::from_parts(status_code, headers, Bytes::from_static(b"2345"))
+    }
+
+    #[test]
+    fn it_should_pass_for_partial_content_with_content_range() {
+        new_response(StatusCode::PARTIAL_CONTENT, Some("bytes 2-5/16")).assert_partial_content();
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_for_other_status_codes() {
+        new_response(StatusCode::OK, Some("bytes 2-5/16")).assert_partial_content();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected a valid 'Content-Range' header for partial content, received None, for request GET <unknown>"
+    )]
+    fn it_should_panic_without_content_range() {
+        new_response(StatusCode::PARTIAL_CONTENT, None).assert_partial_content();
+    }
+}
+
+#[cfg(test)]
+mod test_assert_status_msg {
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::http::StatusCode;
+
+    use crate::TestServer;
+
+    async fn route_get_created() -> StatusCode {
+        StatusCode::CREATED
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_matching_status() {
+        let router = Router::new().route("/created", get(route_get_created));
+        let server = TestServer::new(router).unwrap();
+
+        server
+            .get("/created")
+            .await
+            .assert_status_msg(StatusCode::CREATED, "after creating the user");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "after creating the user: Expected status code 200 (OK), got 201 (Created), for request GET /created"
+    )]
+    async fn it_should_panic_with_custom_message() {
+        let router = Router::new().route("/created", get(route_get_created));
+        let server = TestServer::new(router).unwrap();
+
+        server
+            .get("/created")
+            .await
+            .assert_status_msg(StatusCode::OK, "after creating the user");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_status_one_of {
+    use ::axum::extract::Query;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::http::StatusCode;
+    use ::serde::Deserialize;
+
+    use crate::TestServer;
+
+    #[derive(Deserialize)]
+    struct ItemsQuery {
+        has_items: bool,
+    }
+
+    async fn route_get_items(Query(query): Query<ItemsQuery>) -> StatusCode {
+        match query.has_items {
+            true => StatusCode::OK,
+            false => StatusCode::NO_CONTENT,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_either_status() {
+        let router = Router::new().route("/items", get(route_get_items));
+        let server = TestServer::new(router).unwrap();
+
+        let allowed = [StatusCode::OK, StatusCode::NO_CONTENT];
+
+        server
+            .get("/items")
+            .add_query_param("has_items", true)
+            .await
+            .assert_status_one_of(&allowed);
+        server
+            .get("/items")
+            .add_query_param("has_items", false)
+            .await
+            .assert_status_one_of(&allowed);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected status code to be one of 200 (OK), 202 (Accepted), got 204 (No Content), for request GET /items"
+    )]
+    async fn it_should_panic_if_status_is_not_allowed() {
+        let router = Router::new().route("/items", get(route_get_items));
+        let server = TestServer::new(router).unwrap();
+
+        server
+            .get("/items")
+            .add_query_param("has_items", false)
+            .await
+            .assert_status_one_of(&[StatusCode::OK, StatusCode::ACCEPTED]);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_into_bytes {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_json() -> Json<Value> {
+        Json(json!({
+            "message": "it works?"
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_deserialize_into_json() {
+        let app = Router::new().route(&"/json", get(route_get_json));
+
+        let server = TestServer::new(app).unwrap();
+
+        let bytes = server.get(&"/json").await.into_bytes();
+        let text = String::from_utf8_lossy(&bytes);
+
+        assert_eq!(text, r#"{"message":"it works?"}"#);
+    }
+}
+
+#[cfg(test)]
+mod test_json_value {
+    use crate::TestResponse;
+
+    use ::bytes::Bytes;
+    use ::http::HeaderMap;
+    use ::http::StatusCode;
+    use ::serde_json::json;
+    use ::std::ptr;
+
+    fn new_response(body: &'static str) -> TestResponse {
+        TestResponse::from_parts(
+            StatusCode::OK,
+            HeaderMap::new(),
+            Bytes::from_static(body.as_bytes()),
+        )
+    }
+
+    #[test]
+    fn it_should_parse_body_as_json() {
+        let response = new_response(r#"{ "name": "Joe", "age": 20 }"#);
+
+        assert_eq!(response.json_value(), &json!({ "name": "Joe", "age": 20 }));
+    }
+
+    #[test]
+    fn it_should_only_parse_the_body_once() {
+        let response = new_response(r#"{ "name": "Joe", "age": 20 }"#);
+
+        let first = response.json_value();
+        response.assert_json_path("name", "Joe");
+        response.assert_json_int_at("age", 20);
+        let second = response.json_value();
+
+        assert!(ptr::eq(first, second));
+    }
+
+    #[test]
+    #[should_panic(expected = "Deserializing response from Json, for request GET <unknown>")]
+    fn it_should_panic_for_invalid_json() {
+        let _ = new_response("not json").json_value();
+    }
+}
+
+#[cfg(test)]
+mod test_into_deserialized {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::axum::Json;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    fn example_response() -> ExampleResponse {
+        ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_consume_into_json() {
+        let app = Router::new()
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/form", get(|| async { Form(example_response()) }));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/json").await;
+
+        let json = response.into_json::<ExampleResponse>();
+
+        assert_eq!(json, example_response());
+    }
+
+    #[tokio::test]
+    async fn it_should_consume_into_text() {
+        let app = Router::new()
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/form", get(|| async { Form(example_response()) }));
+        let server = TestServer::new(app).unwrap();
+
+        let text = server.get("/json").await.into_text();
+
+        assert_eq!(text, r#"{"name":"Joe","age":20}"#);
+    }
+
+    #[tokio::test]
+    async fn it_should_consume_into_form() {
+        let app = Router::new()
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/form", get(|| async { Form(example_response()) }));
+        let server = TestServer::new(app).unwrap();
+
+        let form = server.get("/form").await.into_form::<ExampleResponse>();
+
+        assert_eq!(form, example_response());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Deserializing response from Json, for request GET /form")]
+    async fn it_should_panic_with_request_context_when_invalid() {
+        let app = Router::new()
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/form", get(|| async { Form(example_response()) }));
+        let server = TestServer::new(app).unwrap();
+
+        let _ = server.get("/form").await.into_json::<ExampleResponse>();
+    }
+}
+
+#[cfg(test)]
+mod test_save_to_file {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::std::fs;
+
+    async fn route_get_bytes() -> Vec<u8> {
+        vec![0x89, 0x50, 0x4e, 0x47, 0x00, 0xff]
+    }
+
+    #[tokio::test]
+    async fn it_should_write_the_response_bytes_to_file() {
+        let app = Router::new().route("/bytes", get(route_get_bytes));
+        let server = TestServer::new(app).unwrap();
+        let path = ::std::env::temp_dir().join(format!(
+            "axum-test-save-to-file-{}.bin",
+            ::std::process::id()
+        ));
+
+        server
+            .get("/bytes")
+            .await
+            .save_to_file(&path)
+            .expect("Should save response to file");
+        let file_bytes = fs::read(&path).expect("Should read saved file");
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(file_bytes, vec![0x89, 0x50, 0x4e, 0x47, 0x00, 0xff]);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_json {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_json() -> Json<ExampleResponse> {
+        Json(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_deserialize_into_json() {
+        let app = Router::new().route(&"/json", get(route_get_json));
+
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get(&"/json").await.json::<ExampleResponse>();
+
+        assert_eq!(
+            response,
+            ExampleResponse {
+                name: "Joe".to_string(),
+                age: 20,
+            }
+        );
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_yaml {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum_yaml::Yaml;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_yaml() -> Yaml<ExampleResponse> {
+        Yaml(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_deserialize_into_yaml() {
+        let app = Router::new().route(&"/yaml", get(route_get_yaml));
+
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get(&"/yaml").await.yaml::<ExampleResponse>();
+
+        assert_eq!(
+            response,
+            ExampleResponse {
+                name: "Joe".to_string(),
+                age: 20,
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_decode {
+    use ::axum::body::Body;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::axum::Json;
+    use ::http::header::CONTENT_TYPE;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    use crate::TestServer;
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    fn example() -> ExampleResponse {
+        ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        }
+    }
+
+    async fn route_get_json() -> Json<ExampleResponse> {
+        Json(example())
+    }
+
+    async fn route_get_problem_json() -> ([(&'static str, &'static str); 1], &'static str) {
+        (
+            [("content-type", "application/problem+json")],
+            r#"{"name":"Joe","age":20}"#,
+        )
+    }
+
+    async fn route_get_form() -> Form<ExampleResponse> {
+        Form(example())
+    }
+
+    async fn route_get_text() -> &'static str {
+        "name=Joe&age=20"
+    }
+
+    async fn route_get_no_content_type() -> Body {
+        Body::from(r#"{"name":"Joe","age":20}"#)
+    }
+
+    #[tokio::test]
+    async fn it_should_decode_json() {
+        let app = Router::new()
+            .route("/json", get(route_get_json))
+            .route("/problem_json", get(route_get_problem_json))
+            .route("/form", get(route_get_form))
+            .route("/text", get(route_get_text))
+            .route("/no_content_type", get(route_get_no_content_type));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let response = server.get("/json").await;
+
+        assert_eq!(response.decode::<ExampleResponse>(), example());
+    }
+
+    #[tokio::test]
+    async fn it_should_decode_json_suffix_types() {
+        let app = Router::new()
+            .route("/json", get(route_get_json))
+            .route("/problem_json", get(route_get_problem_json))
+            .route("/form", get(route_get_form))
+            .route("/text", get(route_get_text))
+            .route("/no_content_type", get(route_get_no_content_type));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let response = server.get("/problem_json").await;
+
+        assert_eq!(response.decode::<ExampleResponse>(), example());
+    }
+
+    #[tokio::test]
+    async fn it_should_decode_forms() {
+        let app = Router::new()
+            .route("/json", get(route_get_json))
+            .route("/problem_json", get(route_get_problem_json))
+            .route("/form", get(route_get_form))
+            .route("/text", get(route_get_text))
+            .route("/no_content_type", get(route_get_no_content_type));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let response = server.get("/form").await;
+
+        assert_eq!(
+            response.header(CONTENT_TYPE),
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(response.decode::<ExampleResponse>(), example());
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Cannot decode response with unsupported content type 'text/plain; charset=utf-8', for request GET /text"
+    )]
+    async fn it_should_panic_for_unsupported_content_types() {
+        let app = Router::new()
+            .route("/json", get(route_get_json))
+            .route("/problem_json", get(route_get_problem_json))
+            .route("/form", get(route_get_form))
+            .route("/text", get(route_get_text))
+            .route("/no_content_type", get(route_get_no_content_type));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let _ = server.get("/text").await.decode::<ExampleResponse>();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Cannot decode response without a 'content-type' header, for request GET /no_content_type"
+    )]
+    async fn it_should_panic_without_a_content_type() {
+        let app = Router::new()
+            .route("/json", get(route_get_json))
+            .route("/problem_json", get(route_get_problem_json))
+            .route("/form", get(route_get_form))
+            .route("/text", get(route_get_text))
+            .route("/no_content_type", get(route_get_no_content_type));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let _ = server
+            .get("/no_content_type")
+            .await
+            .decode::<ExampleResponse>();
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[cfg(test)]
+mod test_decode_yaml {
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum_yaml::Yaml;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    use crate::TestServer;
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_yaml() -> Yaml<ExampleResponse> {
+        Yaml(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_decode_yaml() {
+        let app = Router::new().route("/yaml", get(route_get_yaml));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let response = server.get("/yaml").await.decode::<ExampleResponse>();
+
+        assert_eq!(
+            response,
+            ExampleResponse {
+                name: "Joe".to_string(),
+                age: 20,
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_form {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_form() -> Form<ExampleResponse> {
+        Form(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_deserialize_into_form() {
+        let app = Router::new().route(&"/form", get(route_get_form));
+
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get(&"/form").await.form::<ExampleResponse>();
+
+        assert_eq!(
+            response,
+            ExampleResponse {
+                name: "Joe".to_string(),
+                age: 20,
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_strict_form {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::axum::Json;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    fn example_response() -> ExampleResponse {
+        ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_deserialize_form_content_type() {
+        let app = Router::new()
+            .route("/form", get(|| async { Form(example_response()) }))
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/text", get(|| async { "name=Joe&age=20" }));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/form").await.strict_form::<ExampleResponse>();
+
+        assert_eq!(response, example_response());
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected content type 'application/x-www-form-urlencoded', received 'application/json', for request GET /json"
+    )]
+    async fn it_should_panic_for_json_content_type() {
+        let app = Router::new()
+            .route("/form", get(|| async { Form(example_response()) }))
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/text", get(|| async { "name=Joe&age=20" }));
+        let server = TestServer::new(app).unwrap();
+
+        let _ = server.get("/json").await.strict_form::<ExampleResponse>();
+    }
+
+    #[tokio::test]
+    async fn it_should_leave_lenient_form_unchanged() {
+        let app = Router::new()
+            .route("/form", get(|| async { Form(example_response()) }))
+            .route("/json", get(|| async { Json(example_response()) }))
+            .route("/text", get(|| async { "name=Joe&age=20" }));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/text").await.form::<ExampleResponse>();
+
+        assert_eq!(response, example_response());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_json {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::axum::Json;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_form() -> Form<ExampleResponse> {
+        Form(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    async fn route_get_json() -> Json<ExampleResponse> {
+        Json(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_match_json_returned() {
+        let app = Router::new().route(&"/json", get(route_get_json));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/json").await.assert_json(&ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        });
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_response_is_different() {
+        let app = Router::new().route(&"/json", get(route_get_json));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/json").await.assert_json(&ExampleResponse {
+            name: "Julia".to_string(),
+            age: 25,
+        });
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_response_is_form() {
+        let app = Router::new().route(&"/form", get(route_get_form));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/form").await.assert_json(&ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_exact {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::serde_json::json;
+
+    async fn route_get_json() -> &'static str {
+        r#"{"name":"Joe","pets":[{"name":"Rex","age":3},{"name":"Spot","age":5}]}"#
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_with_reordered_keys() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_exact(&json!({
+            "pets": [
+                { "age": 3, "name": "Rex" },
+                { "age": 5, "name": "Spot" },
+            ],
+            "name": "Joe",
+        }));
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to match, at 'pets[1].age', expected 6, received 5, for request GET /json"
+    )]
+    async fn it_should_panic_if_values_are_changed() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_exact(&json!({
+            "name": "Joe",
+            "pets": [
+                { "name": "Rex", "age": 3 },
+                { "name": "Spot", "age": 6 },
+            ],
+        }));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "at 'pets[0].age', expected 5, received 3")]
+    async fn it_should_panic_if_arrays_are_reordered() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_exact(&json!({
+            "name": "Joe",
+            "pets": [
+                { "name": "Spot", "age": 5 },
+                { "name": "Rex", "age": 3 },
+            ],
+        }));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "at 'owner', key is missing")]
+    async fn it_should_panic_if_keys_are_missing() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_exact(&json!({
+            "name": "Joe",
+            "owner": "Julia",
+            "pets": [
+                { "name": "Rex", "age": 3 },
+                { "name": "Spot", "age": 5 },
+            ],
+        }));
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_matches_file {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+    use ::std::fs;
+    use ::std::path::Path;
+
+    async fn route_get_user() -> Json<Value> {
+        Json(json!({
+            "pets": ["Rex", "Spot"],
+            "age": 20,
+            "name": "Joe",
+        }))
+    }
+
+    async fn route_get_other_user() -> Json<Value> {
+        Json(json!({
+            "name": "Julia",
+            "age": 20,
+            "pets": ["Rex", "Spot"],
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_matching_golden_file() {
+        let app = Router::new()
+            .route("/user", get(route_get_user))
+            .route("/other_user", get(route_get_other_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_matches_file("tests/fixtures/user.json");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to match golden file \"tests/fixtures/user.json\", at 'name', expected \"Joe\", received \"Julia\", for request GET /other_user"
+    )]
+    async fn it_should_panic_for_mismatching_golden_file() {
+        let app = Router::new()
+            .route("/user", get(route_get_user))
+            .route("/other_user", get(route_get_other_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/other_user")
+            .await
+            .assert_json_matches_file("tests/fixtures/user.json");
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Reading golden file \"tests/fixtures/missing.json\"")]
+    async fn it_should_panic_if_golden_file_is_missing() {
+        let app = Router::new()
+            .route("/user", get(route_get_user))
+            .route("/other_user", get(route_get_other_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_matches_file("tests/fixtures/missing.json");
+    }
+
+    #[tokio::test]
+    async fn it_should_overwrite_golden_file_when_updating() {
+        let path = ::std::env::temp_dir().join("axum-test-golden-user.json");
+        fs::write(&path, "{}").unwrap();
+
+        let app = Router::new()
+            .route("/user", get(route_get_user))
+            .route("/other_user", get(route_get_other_user));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/other_user").await;
+        response.assert_json_matches_file_inner(Path::new(&path), true);
+        response.assert_json_matches_file_inner(Path::new(&path), false);
+
+        let written = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(written.contains(r#""name": "Julia""#));
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_subset_of {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_user() -> Json<Value> {
+        Json(json!({
+            "name": "Joe",
+            "password": "hunter2",
+            "pets": [{ "name": "Rex", "token": "abc" }],
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_when_response_is_within_superset() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_subset_of(&json!({
+            "name": "Joe",
+            "password": "hunter2",
+            "age": 20,
+            "pets": [{ "name": "Rex", "token": "abc", "age": 3 }],
+        }));
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to be a subset, at 'password', key was not expected; at 'pets[0].token', key was not expected, for request GET /user"
+    )]
+    async fn it_should_panic_listing_extra_fields() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_subset_of(&json!({
+            "name": "Joe",
+            "pets": [{ "name": "Rex" }],
+        }));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "at 'name', expected \"Julia\", received \"Joe\"")]
+    async fn it_should_panic_if_values_differ() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_subset_of(&json!({
+            "name": "Julia",
+            "password": "hunter2",
+            "pets": [{ "name": "Rex", "token": "abc" }],
+        }));
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_approx {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_json() -> Json<Value> {
+        Json(json!({
+            "name": "Joe",
+            "price": 0.1 + 0.2,
+            "items": [1.0 / 3.0, 2],
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_match_numbers_within_epsilon() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_approx(
+            &json!({
+                "name": "Joe",
+                "price": 0.3,
+                "items": [0.333, 2],
+            }),
+            0.001,
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "at 'items[0]', expected 0.3, received 0.3333333333333333")]
+    async fn it_should_panic_if_numbers_are_outside_epsilon() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_approx(
+            &json!({
+                "name": "Joe",
+                "price": 0.3,
+                "items": [0.3, 2],
+            }),
+            0.001,
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "at 'name'")]
+    async fn it_should_panic_if_non_numeric_values_differ() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_approx(
+            &json!({
+                "name": "Julia",
+                "price": 0.3,
+                "items": [0.333, 2],
+            }),
+            0.001,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_ignoring {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct User {
+        id: u32,
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_user() -> Json<User> {
+        Json(User {
+            id: 8_472,
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_match_ignoring_generated_id() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        let expected = User {
+            id: 0,
+            name: "Joe".to_string(),
+            age: 20,
+        };
+
+        server
+            .get("/user")
+            .await
+            .assert_json_ignoring(&expected, &["id"]);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to match, at 'age', expected 25, received 20, for request GET /user"
+    )]
+    async fn it_should_panic_if_other_fields_differ() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        let expected = User {
+            id: 0,
+            name: "Joe".to_string(),
+            age: 25,
+        };
+
+        server
+            .get("/user")
+            .await
+            .assert_json_ignoring(&expected, &["id"]);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_array_len {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_array() -> Json<Value> {
+        Json(json!([1, 2, 3]))
+    }
+
+    async fn route_get_object() -> Json<Value> {
+        Json(json!({ "name": "Joe" }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_if_array_length_matches() {
+        let app = Router::new()
+            .route("/array", get(route_get_array))
+            .route("/object", get(route_get_object));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/array").await.assert_json_array_len(3);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json array of length 2, received length 3, for request GET /array"
+    )]
+    async fn it_should_panic_if_array_length_differs() {
+        let app = Router::new()
+            .route("/array", get(route_get_array))
+            .route("/object", get(route_get_object));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/array").await.assert_json_array_len(2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = r#"Expected Json array of length 1, received {"name":"Joe"}"#)]
+    async fn it_should_panic_if_not_an_array() {
+        let app = Router::new()
+            .route("/array", get(route_get_array))
+            .route("/object", get(route_get_object));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/object").await.assert_json_array_len(1);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_object_len {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_array() -> Json<Value> {
+        Json(json!([1, 2, 3]))
+    }
+
+    async fn route_get_object() -> Json<Value> {
+        Json(json!({ "name": "Joe", "age": 20 }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_if_number_of_keys_matches() {
+        let app = Router::new()
+            .route("/array", get(route_get_array))
+            .route("/object", get(route_get_object));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/object").await.assert_json_object_len(2);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json object with 3 keys, received 2 keys, for request GET /object"
+    )]
+    async fn it_should_panic_if_number_of_keys_differs() {
+        let app = Router::new()
+            .route("/array", get(route_get_array))
+            .route("/object", get(route_get_object));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/object").await.assert_json_object_len(3);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected Json object with 3 keys, received [1,2,3]")]
+    async fn it_should_panic_if_not_an_object() {
+        let app = Router::new()
+            .route("/array", get(route_get_array))
+            .route("/object", get(route_get_object));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/array").await.assert_json_object_len(3);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_has_keys {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_user() -> Json<Value> {
+        Json(json!({
+            "id": 123,
+            "user": {
+                "name": "Joe",
+                "email": "joe@example.com",
+            },
+            "pets": [{ "name": "Rex" }],
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_if_all_keys_are_present() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_has_keys(&["id", "user", "user.email"]);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to contain keys, missing 'name', 'user.age', for request GET /user"
+    )]
+    async fn it_should_panic_listing_missing_keys() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_has_keys(&["id", "name", "user.email", "user.age"]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "missing 'id.value'")]
+    async fn it_should_panic_if_dotted_path_goes_through_non_object() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_has_keys(&["id.value"]);
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_keys_within_arrays() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_has_keys(&["pets[0]", "pets[0].name"]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "missing 'pets[1].name'")]
+    async fn it_should_panic_for_keys_missing_within_arrays() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_has_keys(&["pets[1].name"]);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_path {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_users() -> Json<Value> {
+        Json(json!({
+            "users": [
+                { "name": "Joe", "age": 20 },
+            ],
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_matching_values() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/users").await;
+
+        response.assert_json_path("users[0].name", "Joe");
+        response.assert_json_path("users[0].age", &20);
+        response.assert_json_path("users[0]", &json!({ "name": "Joe", "age": 20 }));
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'users[0].name' to be \"Julia\", found \"Joe\", for request GET /users"
+    )]
+    async fn it_should_panic_for_different_values() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/users")
+            .await
+            .assert_json_path("users[0].name", "Julia");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'users[1].name' to be \"Joe\", it is missing, for request GET /users"
+    )]
+    async fn it_should_panic_for_missing_paths() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/users")
+            .await
+            .assert_json_path("users[1].name", "Joe");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_number_at {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_item() -> Json<Value> {
+        Json(json!({ "name": "Apple", "count": 20, "price": 1.5, "weight": 2.0 }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_integer_and_float_fields() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/item").await;
+
+        response.assert_json_number_at("count", 20.0);
+        response.assert_json_number_at("price", 1.5);
+        response.assert_json_number_at("weight", 2.0);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'price' to be 2.5, found 1.5, for request GET /item"
+    )]
+    async fn it_should_panic_for_different_number() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/item")
+            .await
+            .assert_json_number_at("price", 2.5);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'name' to be a number, found \"Apple\", for request GET /item"
+    )]
+    async fn it_should_panic_for_non_numbers() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/item").await.assert_json_number_at("name", 1.0);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'size' to be a number, it is missing, for request GET /item"
+    )]
+    async fn it_should_panic_for_missing_paths() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/item").await.assert_json_number_at("size", 1.0);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_int_at {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_item() -> Json<Value> {
+        Json(json!({ "count": 20, "offset": -5, "weight": 2.0 }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_integer_fields() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/item").await;
+
+        response.assert_json_int_at("count", 20);
+        response.assert_json_int_at("offset", -5);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'count' to be 21, found 20, for request GET /item"
+    )]
+    async fn it_should_panic_for_different_integer() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/item").await.assert_json_int_at("count", 21);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'weight' to be an integer, found 2.0, for request GET /item"
+    )]
+    async fn it_should_panic_for_float_fields() {
+        let app = Router::new().route("/item", get(route_get_item));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/item").await.assert_json_int_at("weight", 2);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_at {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_user() -> Json<Value> {
+        Json(json!({ "name": "Joe", "age": 20 }))
+    }
+
+    fn is_adult_age(age: &Value) -> bool {
+        age.as_u64().is_some_and(|age| (18..=120).contains(&age))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_when_predicate_returns_true() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_at("age", is_adult_age);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'name' to pass the predicate given, found \"Joe\", for request GET /user"
+    )]
+    async fn it_should_panic_when_predicate_returns_false() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/user")
+            .await
+            .assert_json_at("name", is_adult_age);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'height' to pass the predicate given, it is missing, for request GET /user"
+    )]
+    async fn it_should_panic_when_path_is_missing() {
+        let app = Router::new().route("/user", get(route_get_user));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_at("height", |_| true);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_error {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::http::StatusCode;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_error() -> (StatusCode, Json<Value>) {
+        (
+            StatusCode::NOT_FOUND,
+            Json(json!({
+                "error": { "code": "user_not_found", "message": "No user found" },
+            })),
+        )
+    }
+
+    async fn route_get_ok() -> Json<Value> {
+        Json(json!({ "name": "Joe" }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_matching_error_code() {
+        let app = Router::new()
+            .route("/error", get(route_get_error))
+            .route("/ok", get(route_get_ok));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/error")
+            .expect_failure()
+            .await
+            .assert_json_error("user_not_found");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json error code 'forbidden', found \"user_not_found\" at 'error.code', with body {\"error\":{\"code\":\"user_not_found\",\"message\":\"No user found\"}}, for request GET /error"
+    )]
+    async fn it_should_panic_for_different_error_code() {
+        let app = Router::new()
+            .route("/error", get(route_get_error))
+            .route("/ok", get(route_get_ok));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/error")
+            .expect_failure()
+            .await
+            .assert_json_error("forbidden");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json error code 'forbidden', found nothing at 'error.code', with body {\"name\":\"Joe\"}, for request GET /ok"
+    )]
+    async fn it_should_panic_for_responses_without_an_error() {
+        let app = Router::new()
+            .route("/error", get(route_get_error))
+            .route("/ok", get(route_get_ok));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/ok").await.assert_json_error("forbidden");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_array_unique_by {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_unique() -> Json<Value> {
+        Json(json!([{ "id": 1 }, { "id": 3 }, { "id": 2 }]))
+    }
+
+    async fn route_get_duplicates() -> Json<Value> {
+        Json(json!([{ "id": 1 }, { "id": 2 }, { "id": 1 }]))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_unique_values() {
+        let app = Router::new()
+            .route("/unique", get(route_get_unique))
+            .route("/duplicates", get(route_get_duplicates));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/unique")
+            .await
+            .assert_json_array_unique_by("id");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json array items to be unique by 'id', found 1 at index 0 and 2, for request GET /duplicates"
+    )]
+    async fn it_should_panic_for_duplicate_values() {
+        let app = Router::new()
+            .route("/unique", get(route_get_unique))
+            .route("/duplicates", get(route_get_duplicates));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/duplicates")
+            .await
+            .assert_json_array_unique_by("id");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'user.id' in array item at index 0, it is missing, for request GET /unique"
+    )]
+    async fn it_should_panic_for_missing_paths() {
+        let app = Router::new()
+            .route("/unique", get(route_get_unique))
+            .route("/duplicates", get(route_get_duplicates));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/unique")
+            .await
+            .assert_json_array_unique_by("user.id");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_array_sorted_by {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_sorted() -> Json<Value> {
+        Json(json!([
+            { "id": 1, "name": "Alice" },
+            { "id": 2, "name": "Bob" },
+            { "id": 2, "name": "Carol" },
+        ]))
+    }
+
+    async fn route_get_unsorted() -> Json<Value> {
+        Json(json!([{ "id": 1 }, { "id": 3 }, { "id": 2 }]))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_ascending_values() {
+        let app = Router::new()
+            .route("/sorted", get(route_get_sorted))
+            .route("/unsorted", get(route_get_unsorted));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/sorted").await;
+
+        response.assert_json_array_sorted_by("id", true);
+        response.assert_json_array_sorted_by("name", true);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json array items to be sorted descending by 'id', found 1 at index 0 followed by 2 at index 1, for request GET /sorted"
+    )]
+    async fn it_should_panic_for_ascending_values_when_descending() {
+        let app = Router::new()
+            .route("/sorted", get(route_get_sorted))
+            .route("/unsorted", get(route_get_unsorted));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/sorted")
+            .await
+            .assert_json_array_sorted_by("id", false);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json array items to be sorted ascending by 'id', found 3 at index 1 followed by 2 at index 2, for request GET /unsorted"
+    )]
+    async fn it_should_panic_for_unsorted_values() {
+        let app = Router::new()
+            .route("/sorted", get(route_get_sorted))
+            .route("/unsorted", get(route_get_unsorted));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/unsorted")
+            .await
+            .assert_json_array_sorted_by("id", true);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_path_absent {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_users() -> Json<Value> {
+        Json(json!({
+            "users": [
+                { "name": "Joe", "password": "hunter2", "email": null },
+            ],
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_absent_paths() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/users").await;
+
+        response.assert_json_path_absent("users[0].token");
+        response.assert_json_path_absent("users[1].password");
+        response.assert_json_path_absent("owner.password");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'users[0].password' to be absent, found \"hunter2\", for request GET /users"
+    )]
+    async fn it_should_panic_for_present_paths() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/users")
+            .await
+            .assert_json_path_absent("users[0].password");
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected Json path 'users[0].email' to be absent, found null")]
+    async fn it_should_panic_for_null_values() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/users")
+            .await
+            .assert_json_path_absent("users[0].email");
+    }
+
+    #[tokio::test]
+    async fn it_should_allow_null_values_when_using_or_null() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/users")
+            .await
+            .assert_json_path_absent_or_null("users[0].email");
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected Json path 'users[0].password' to be absent")]
+    async fn it_should_panic_for_present_values_when_using_or_null() {
+        let app = Router::new().route("/users", get(route_get_users));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/users")
+            .await
+            .assert_json_path_absent_or_null("users[0].password");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_null_at {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+
+    #[tokio::test]
+    async fn it_should_pass_for_null_values() {
+        let app = Router::new().route(
+            "/user",
+            get(|| async { Json(json!({ "name": "Joe", "email": null })) }),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_null_at("email");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'name' to be null, found \"Joe\", for request GET /user"
+    )]
+    async fn it_should_panic_for_non_null_values() {
+        let app = Router::new().route(
+            "/user",
+            get(|| async { Json(json!({ "name": "Joe", "email": null })) }),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_null_at("name");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'phone' to be present, it is missing, for request GET /user"
+    )]
+    async fn it_should_panic_for_missing_paths() {
+        let app = Router::new().route(
+            "/user",
+            get(|| async { Json(json!({ "name": "Joe", "email": null })) }),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_null_at("phone");
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_not_null_for_values() {
+        let app = Router::new().route(
+            "/user",
+            get(|| async { Json(json!({ "name": "Joe", "email": null })) }),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_not_null_at("name");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'email' to not be null, found null, for request GET /user"
+    )]
+    async fn it_should_panic_not_null_for_null_values() {
+        let app = Router::new().route(
+            "/user",
+            get(|| async { Json(json!({ "name": "Joe", "email": null })) }),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_not_null_at("email");
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json path 'phone' to be present, it is missing, for request GET /user"
+    )]
+    async fn it_should_panic_not_null_for_missing_paths() {
+        let app = Router::new().route(
+            "/user",
+            get(|| async { Json(json!({ "name": "Joe", "email": null })) }),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/user").await.assert_json_not_null_at("phone");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_empty {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+
+    #[tokio::test]
+    async fn it_should_pass_for_empty_object() {
+        let app = Router::new()
+            .route("/empty-object", get(|| async { Json(json!({})) }))
+            .route("/empty-array", get(|| async { Json(json!([])) }))
+            .route("/object", get(|| async { Json(json!({ "name": "Joe" })) }))
+            .route("/array", get(|| async { Json(json!(["Joe"])) }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/empty-object").await.assert_json_empty_object();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to be an empty object, found {\"name\":\"Joe\"}, for request GET /object"
+    )]
+    async fn it_should_panic_for_non_empty_object() {
+        let app = Router::new()
+            .route("/empty-object", get(|| async { Json(json!({})) }))
+            .route("/empty-array", get(|| async { Json(json!([])) }))
+            .route("/object", get(|| async { Json(json!({ "name": "Joe" })) }))
+            .route("/array", get(|| async { Json(json!(["Joe"])) }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/object").await.assert_json_empty_object();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to be an empty object, found [], for request GET /empty-array"
+    )]
+    async fn it_should_panic_for_array_when_expecting_object() {
+        let app = Router::new()
+            .route("/empty-object", get(|| async { Json(json!({})) }))
+            .route("/empty-array", get(|| async { Json(json!([])) }))
+            .route("/object", get(|| async { Json(json!({ "name": "Joe" })) }))
+            .route("/array", get(|| async { Json(json!(["Joe"])) }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/empty-array").await.assert_json_empty_object();
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_empty_array() {
+        let app = Router::new()
+            .route("/empty-object", get(|| async { Json(json!({})) }))
+            .route("/empty-array", get(|| async { Json(json!([])) }))
+            .route("/object", get(|| async { Json(json!({ "name": "Joe" })) }))
+            .route("/array", get(|| async { Json(json!(["Joe"])) }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/empty-array").await.assert_json_empty_array();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to be an empty array, found [\"Joe\"], for request GET /array"
+    )]
+    async fn it_should_panic_for_non_empty_array() {
+        let app = Router::new()
+            .route("/empty-object", get(|| async { Json(json!({})) }))
+            .route("/empty-array", get(|| async { Json(json!([])) }))
+            .route("/object", get(|| async { Json(json!({ "name": "Joe" })) }))
+            .route("/array", get(|| async { Json(json!(["Joe"])) }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/array").await.assert_json_empty_array();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected Json to be an empty array, found {}, for request GET /empty-object"
+    )]
+    async fn it_should_panic_for_object_when_expecting_array() {
+        let app = Router::new()
+            .route("/empty-object", get(|| async { Json(json!({})) }))
+            .route("/empty-array", get(|| async { Json(json!([])) }))
+            .route("/object", get(|| async { Json(json!({ "name": "Joe" })) }))
+            .route("/array", get(|| async { Json(json!(["Joe"])) }));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/empty-object").await.assert_json_empty_array();
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_yaml {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::axum_yaml::Yaml;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_form() -> Form<ExampleResponse> {
+        Form(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    async fn route_get_yaml() -> Yaml<ExampleResponse> {
+        Yaml(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_match_yaml_returned() {
+        let app = Router::new().route(&"/yaml", get(route_get_yaml));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/yaml").await.assert_yaml(&ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        });
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_response_is_different() {
+        let app = Router::new().route(&"/yaml", get(route_get_yaml));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/yaml").await.assert_yaml(&ExampleResponse {
+            name: "Julia".to_string(),
+            age: 25,
+        });
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_response_is_form() {
+        let app = Router::new().route(&"/form", get(route_get_form));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/form").await.assert_yaml(&ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        });
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod test_assert_form {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Form;
+    use ::axum::Json;
+    use ::serde::Deserialize;
+    use ::serde::Serialize;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct ExampleResponse {
+        name: String,
+        age: u32,
+    }
+
+    async fn route_get_form() -> Form<ExampleResponse> {
+        Form(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    async fn route_get_json() -> Json<ExampleResponse> {
+        Json(ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_match_form_returned() {
+        let app = Router::new().route(&"/form", get(route_get_form));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/form").await.assert_form(&ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        });
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_response_is_different() {
+        let app = Router::new().route(&"/form", get(route_get_form));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/form").await.assert_form(&ExampleResponse {
+            name: "Julia".to_string(),
+            age: 25,
+        });
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn it_should_panic_if_response_is_json() {
+        let app = Router::new().route(&"/json", get(route_get_json));
+
+        let server = TestServer::new(app).unwrap();
+
+        server.get(&"/json").await.assert_form(&ExampleResponse {
+            name: "Joe".to_string(),
+            age: 20,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test_as_str {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::bytes::Bytes;
+
+    async fn route_get_text() -> &'static str {
+        "hello! 👋"
+    }
+
+    async fn route_get_invalid_utf8() -> Bytes {
+        Bytes::from_static(&[0x68, 0x69, 0xff])
+    }
+
+    #[tokio::test]
+    async fn it_should_match_text() {
+        let app = Router::new()
+            .route("/text", get(route_get_text))
+            .route("/invalid", get(route_get_invalid_utf8));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/text").await;
+
+        assert_eq!(response.as_str().unwrap(), "hello! 👋");
+        assert_eq!(response.as_str().unwrap(), response.text());
+    }
+
+    #[tokio::test]
+    async fn it_should_error_on_invalid_utf8() {
+        let app = Router::new()
+            .route("/text", get(route_get_text))
+            .route("/invalid", get(route_get_invalid_utf8));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/invalid").await;
+
+        assert!(response.as_str().is_err());
+        assert_eq!(response.text(), "hi\u{FFFD}");
+    }
+}
+
+#[cfg(test)]
+mod test_text {
+    use crate::TestServer;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    #[tokio::test]
+    async fn it_should_deserialize_into_text() {
+        async fn route_get_text() -> String {
+            "hello!".to_string()
+        }
+
+        let app = Router::new().route("/text", get(route_get_text));
+
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/text").await.text();
+
+        assert_eq!(response, "hello!");
+    }
+}
+
+#[cfg(test)]
+mod test_assert_is_json {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    async fn route_get_json() -> &'static str {
+        r#"{ "name": "Joe", "pets": [1, 2] }"#
+    }
+
+    async fn route_get_invalid_json() -> &'static str {
+        r#"{ "name": "Joe", "#
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_valid_json() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_is_json();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected response to be valid Json, EOF while parsing a value at line 1 column 17, for request GET /json"
+    )]
+    async fn it_should_panic_for_invalid_json() {
+        let app = Router::new().route("/json", get(route_get_invalid_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_is_json();
+    }
+}
+
+#[cfg(test)]
+mod test_assert_json_roundtrips {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    async fn route_get_json() -> Json<Value> {
+        Json(json!({ "name": "Joe", "price": 0.1, "tags": ["a", "b"] }))
+    }
+
+    async fn route_get_nan() -> &'static str {
+        r#"{ "price": NaN }"#
+    }
+
+    async fn route_get_formatted() -> &'static str {
+        r#" { "price": 1.50, "name": "\u004aoe", "tags": [ ] } "#
+    }
+
+    async fn route_get_large_number() -> &'static str {
+        r#"{ "id": 12345678901234567890123 }"#
+    }
+
+    async fn route_get_duplicate_key() -> &'static str {
+        r#"{ "name": "Joe", "name": "Kim" }"#
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_well_formed_json() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_json_roundtrips();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected Json to roundtrip, failed to parse response")]
+    async fn it_should_panic_for_nan_values() {
+        let app = Router::new().route("/nan", get(route_get_nan));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/nan").await.assert_json_roundtrips();
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_json_written_differently() {
+        let app = Router::new().route("/formatted", get(route_get_formatted));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/formatted").await.assert_json_roundtrips();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = r#"Expected Json to roundtrip, it was serialized as {"id":1.2345678901234568e+22}, for request GET /large-number"#
+    )]
+    async fn it_should_panic_for_numbers_losing_digits() {
+        let app = Router::new().route("/large-number", get(route_get_large_number));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/large-number").await.assert_json_roundtrips();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = r#"Expected Json to roundtrip, it was serialized as {"name":"Kim"}, for request GET /duplicate-key"#
+    )]
+    async fn it_should_panic_for_duplicate_keys() {
+        let app = Router::new().route("/duplicate-key", get(route_get_duplicate_key));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/duplicate-key").await.assert_json_roundtrips();
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[cfg(test)]
+mod test_assert_is_yaml {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    async fn route_get_yaml() -> &'static str {
+        "name: Joe\npets:\n  - 1\n  - 2\n"
+    }
+
+    async fn route_get_invalid_yaml() -> &'static str {
+        "name: [Joe"
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_valid_yaml() {
+        let app = Router::new().route("/yaml", get(route_get_yaml));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/yaml").await.assert_is_yaml();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Expected response to be valid Yaml")]
+    async fn it_should_panic_for_invalid_yaml() {
+        let app = Router::new().route("/yaml", get(route_get_invalid_yaml));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/yaml").await.assert_is_yaml();
+    }
+}
+
+#[cfg(test)]
+mod test_assert_is_form {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    async fn route_get_form() -> &'static str {
+        "name=Joe&age=20&empty="
+    }
+
+    async fn route_get_json() -> &'static str {
+        r#"{ "name": "Joe" }"#
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_valid_form() {
+        let app = Router::new().route("/form", get(route_get_form));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/form").await.assert_is_form();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected response to be a valid form, found field '{ \"name\": \"Joe\" }' which is not a name=value pair, for request GET /json"
+    )]
+    async fn it_should_panic_for_json() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/json").await.assert_is_form();
+    }
+}
+
+#[cfg(test)]
+mod test_assert_header_present {
+    use crate::TestServer;
+
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    async fn route_get_with_header() -> ([(&'static str, &'static str); 1], &'static str) {
+        ([("x-request-id", "8d2f7c4e")], "hello!")
     }
 
-    /// Assert the response status code does **not** match the one given.
-    #[track_caller]
-    pub fn assert_not_status(&self, expected_status_code: StatusCode) {
-        let expected_debug = StatusCodeFormatter(expected_status_code);
-        let request_format = &self.request_format;
+    #[tokio::test]
+    async fn it_should_pass_if_header_is_present() {
+        let app = Router::new().route("/header", get(route_get_with_header));
+        let server = TestServer::new(app).unwrap();
 
-        assert_ne!(
-            expected_status_code,
-            self.status_code(),
-            "Expected status code to not be {expected_debug}, it is, for request {request_format}",
-        );
+        server
+            .get("/header")
+            .await
+            .assert_header_present("x-request-id");
     }
-}
 
-impl From<TestResponse> for Bytes {
-    fn from(response: TestResponse) -> Self {
-        response.into_bytes()
+    #[tokio::test]
+    #[should_panic(expected = "Expected header 'x-request-id' to be present")]
+    async fn it_should_panic_if_header_is_missing() {
+        let app = Router::new().route("/no-header", get(|| async { "hello!" }));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/no-header")
+            .await
+            .assert_header_present("x-request-id");
     }
 }
 
 #[cfg(test)]
-mod test_assert_success {
+mod test_header_as {
+    use crate::TestServer;
+
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::http::StatusCode;
-
-    use crate::TestServer;
 
-    pub async fn route_get_pass() -> StatusCode {
-        StatusCode::OK
+    async fn route_get_with_headers() -> ([(&'static str, &'static str); 2], &'static str) {
+        ([("x-total-count", "42"), ("x-page", "first")], "hello!")
     }
 
-    pub async fn route_get_fail() -> StatusCode {
-        StatusCode::SERVICE_UNAVAILABLE
+    #[tokio::test]
+    async fn it_should_parse_total_count_into_usize() {
+        let app = Router::new().route("/headers", get(route_get_with_headers));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/headers").await;
+
+        assert_eq!(response.header_as::<usize, _>("x-total-count"), Some(42));
+        assert_eq!(response.header_as_required::<usize, _>("x-total-count"), 42);
     }
 
     #[tokio::test]
-    async fn it_should_pass_when_200() {
-        let router = Router::new()
-            .route(&"/pass", get(route_get_pass))
-            .route(&"/fail", get(route_get_fail));
-
-        let server = TestServer::new(router).unwrap();
+    async fn it_should_return_none_if_missing_or_unparseable() {
+        let app = Router::new().route("/headers", get(route_get_with_headers));
+        let server = TestServer::new(app).unwrap();
 
-        let response = server.get(&"/pass").await;
+        let response = server.get("/headers").await;
 
-        response.assert_status_success()
+        assert_eq!(response.header_as::<usize, _>("x-missing"), None);
+        assert_eq!(response.header_as::<usize, _>("x-page"), None);
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_when_not_200() {
-        let router = Router::new()
-            .route(&"/pass", get(route_get_pass))
-            .route(&"/fail", get(route_get_fail));
+    #[should_panic(
+        expected = "Failed to parse header x-page with value 'first', invalid digit found in string, for request GET /headers"
+    )]
+    async fn it_should_panic_if_required_header_cannot_be_parsed() {
+        let app = Router::new().route("/headers", get(route_get_with_headers));
+        let server = TestServer::new(app).unwrap();
 
-        let server = TestServer::new(router).unwrap();
+        let response = server.get("/headers").await;
 
-        let response = server.get(&"/fail").expect_failure().await;
+        let _ = response.header_as_required::<usize, _>("x-page");
+    }
 
-        response.assert_status_success()
+    #[tokio::test]
+    #[should_panic(expected = "Cannot find header x-missing, for request GET /headers")]
+    async fn it_should_panic_if_required_header_is_missing() {
+        let app = Router::new().route("/headers", get(route_get_with_headers));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/headers").await;
+
+        let _ = response.header_as_required::<usize, _>("x-missing");
     }
 }
 
 #[cfg(test)]
-mod test_assert_failure {
+mod test_assert_header_missing {
+    use crate::TestServer;
+
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::http::StatusCode;
-
-    use crate::TestServer;
 
-    pub async fn route_get_pass() -> StatusCode {
-        StatusCode::OK
+    async fn route_get_with_header() -> ([(&'static str, &'static str); 1], &'static str) {
+        ([("x-request-id", "8d2f7c4e")], "hello!")
     }
 
-    pub async fn route_get_fail() -> StatusCode {
-        StatusCode::SERVICE_UNAVAILABLE
+    #[tokio::test]
+    async fn it_should_pass_if_header_is_missing() {
+        let app = Router::new().route("/no-header", get(|| async { "hello!" }));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/no-header")
+            .await
+            .assert_header_missing("x-request-id");
     }
 
     #[tokio::test]
-    async fn it_should_pass_when_not_200() {
-        let router = Router::new()
-            .route(&"/pass", get(route_get_pass))
-            .route(&"/fail", get(route_get_fail));
+    #[should_panic(expected = "Expected header 'x-request-id' to be missing")]
+    async fn it_should_panic_if_header_is_present() {
+        let app = Router::new().route("/header", get(route_get_with_header));
+        let server = TestServer::new(app).unwrap();
 
-        let server = TestServer::new(router).unwrap();
-        let response = server.get(&"/fail").expect_failure().await;
+        server
+            .get("/header")
+            .await
+            .assert_header_missing("x-request-id");
+    }
+}
 
-        response.assert_status_failure()
+#[cfg(test)]
+mod test_assert_header_single {
+    use crate::TestServer;
+
+    use ::axum::response::AppendHeaders;
+    use ::axum::routing::get;
+    use ::axum::routing::Router;
+
+    #[tokio::test]
+    async fn it_should_pass_if_header_is_sent_once() {
+        let app = Router::new()
+            .route(
+                "/single",
+                get(|| async { AppendHeaders([("x-tag", "blue")]) }),
+            )
+            .route(
+                "/duplicate",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-tag", "red")]) }),
+            );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/single").await.assert_header_single("x-tag");
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_when_200() {
-        let router = Router::new()
-            .route(&"/pass", get(route_get_pass))
-            .route(&"/fail", get(route_get_fail));
+    #[should_panic(
+        expected = "Expected header 'x-other' to appear once, it is missing, for request GET /single"
+    )]
+    async fn it_should_panic_if_header_is_missing() {
+        let app = Router::new()
+            .route(
+                "/single",
+                get(|| async { AppendHeaders([("x-tag", "blue")]) }),
+            )
+            .route(
+                "/duplicate",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-tag", "red")]) }),
+            );
+        let server = TestServer::new(app).unwrap();
 
-        let server = TestServer::new(router).unwrap();
-        let response = server.get(&"/pass").await;
+        server.get("/single").await.assert_header_single("x-other");
+    }
 
-        response.assert_status_failure()
+    #[tokio::test]
+    #[should_panic(
+        expected = r#"Expected header 'x-tag' to appear once, received 2 values ["blue", "red"]"#
+    )]
+    async fn it_should_panic_if_header_is_sent_twice() {
+        let app = Router::new()
+            .route(
+                "/single",
+                get(|| async { AppendHeaders([("x-tag", "blue")]) }),
+            )
+            .route(
+                "/duplicate",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-tag", "red")]) }),
+            );
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/duplicate").await.assert_header_single("x-tag");
     }
 }
 
 #[cfg(test)]
-mod test_assert_status {
+mod test_assert_no_duplicate_headers {
+    use crate::TestServer;
+
+    use ::axum::response::AppendHeaders;
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::http::StatusCode;
 
-    use crate::TestServer;
+    #[tokio::test]
+    async fn it_should_pass_if_no_headers_are_duplicated() {
+        let app = Router::new()
+            .route(
+                "/single",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-colour", "red")]) }),
+            )
+            .route(
+                "/cookies",
+                get(|| async {
+                    AppendHeaders([("set-cookie", "first=1"), ("set-cookie", "second=2")])
+                }),
+            )
+            .route(
+                "/duplicate",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-tag", "red")]) }),
+            );
+        let server = TestServer::new(app).unwrap();
 
-    pub async fn route_get_ok() -> StatusCode {
-        StatusCode::OK
+        server.get("/single").await.assert_no_duplicate_headers(&[]);
     }
 
     #[tokio::test]
-    async fn it_should_pass_if_given_right_status_code() {
-        let router = Router::new().route(&"/ok", get(route_get_ok));
-        let server = TestServer::new(router).unwrap();
+    async fn it_should_pass_if_duplicated_header_is_allowed() {
+        let app = Router::new()
+            .route(
+                "/single",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-colour", "red")]) }),
+            )
+            .route(
+                "/cookies",
+                get(|| async {
+                    AppendHeaders([("set-cookie", "first=1"), ("set-cookie", "second=2")])
+                }),
+            )
+            .route(
+                "/duplicate",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-tag", "red")]) }),
+            );
+        let server = TestServer::new(app).unwrap();
 
-        server.get(&"/ok").await.assert_status(StatusCode::OK);
+        server
+            .get("/cookies")
+            .await
+            .assert_no_duplicate_headers(&["Set-Cookie"]);
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_when_status_code_does_not_match() {
-        let router = Router::new().route(&"/ok", get(route_get_ok));
-        let server = TestServer::new(router).unwrap();
+    #[should_panic(expected = r#"Expected no duplicate headers, received 'x-tag' ["blue", "red"]"#)]
+    async fn it_should_panic_if_header_is_duplicated() {
+        let app = Router::new()
+            .route(
+                "/single",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-colour", "red")]) }),
+            )
+            .route(
+                "/cookies",
+                get(|| async {
+                    AppendHeaders([("set-cookie", "first=1"), ("set-cookie", "second=2")])
+                }),
+            )
+            .route(
+                "/duplicate",
+                get(|| async { AppendHeaders([("x-tag", "blue"), ("x-tag", "red")]) }),
+            );
+        let server = TestServer::new(app).unwrap();
 
-        server.get(&"/ok").await.assert_status(StatusCode::ACCEPTED);
+        server
+            .get("/duplicate")
+            .await
+            .assert_no_duplicate_headers(&["set-cookie"]);
     }
 }
 
+#[cfg(feature = "regex")]
 #[cfg(test)]
-mod test_assert_not_status {
+mod test_assert_header_matches {
+    use crate::TestServer;
+
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::http::StatusCode;
+    use ::regex::Regex;
 
-    use crate::TestServer;
+    async fn route_get_request_id() -> ([(&'static str, &'static str); 1], &'static str) {
+        (
+            [("x-request-id", "67e55044-10b1-426f-9247-bb680e5fe0c8")],
+            "hello!",
+        )
+    }
 
-    pub async fn route_get_ok() -> StatusCode {
-        StatusCode::OK
+    fn uuid_regex() -> Regex {
+        Regex::new("^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap()
     }
 
     #[tokio::test]
-    async fn it_should_pass_if_status_code_does_not_match() {
-        let router = Router::new().route(&"/ok", get(route_get_ok));
-        let server = TestServer::new(router).unwrap();
+    async fn it_should_pass_if_header_matches() {
+        let app = Router::new().route("/request_id", get(route_get_request_id));
+        let server = TestServer::new(app).unwrap();
 
         server
-            .get(&"/ok")
+            .get("/request_id")
             .await
-            .assert_not_status(StatusCode::ACCEPTED);
+            .assert_header_matches("x-request-id", &uuid_regex());
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_status_code_matches() {
-        let router = Router::new().route(&"/ok", get(route_get_ok));
-        let server = TestServer::new(router).unwrap();
+    #[should_panic(
+        expected = "Expected header 'x-request-id' to match '^[0-9]+$', received '67e55044-10b1-426f-9247-bb680e5fe0c8', for request GET /request_id"
+    )]
+    async fn it_should_panic_if_header_does_not_match() {
+        let pattern = Regex::new("^[0-9]+$").unwrap();
 
-        server.get(&"/ok").await.assert_not_status(StatusCode::OK);
+        let app = Router::new().route("/request_id", get(route_get_request_id));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/request_id")
+            .await
+            .assert_header_matches("x-request-id", &pattern);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected header 'x-trace-id' to be present, it is missing, for request GET /request_id"
+    )]
+    async fn it_should_panic_if_header_is_missing() {
+        let app = Router::new().route("/request_id", get(route_get_request_id));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/request_id")
+            .await
+            .assert_header_matches("x-trace-id", &uuid_regex());
     }
 }
 
 #[cfg(test)]
-mod test_into_bytes {
+mod test_assert_has_content_type {
     use crate::TestServer;
+
+    use ::axum::body::Body;
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::axum::Json;
-    use ::serde_json::json;
-    use ::serde_json::Value;
+    use ::http::StatusCode;
 
-    async fn route_get_json() -> Json<Value> {
-        Json(json!({
-            "message": "it works?"
-        }))
+    async fn route_get_with_content_type() -> &'static str {
+        "hello!"
+    }
+
+    async fn route_get_without_content_type() -> Body {
+        Body::from("hello!")
+    }
+
+    async fn route_get_empty() -> StatusCode {
+        StatusCode::NO_CONTENT
     }
 
     #[tokio::test]
-    async fn it_should_deserialize_into_json() {
-        let app = Router::new().route(&"/json", get(route_get_json));
+    async fn it_should_pass_if_content_type_is_set() {
+        let app = Router::new()
+            .route("/with", get(route_get_with_content_type))
+            .route("/without", get(route_get_without_content_type))
+            .route("/empty", get(route_get_empty));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/with").await.assert_has_content_type();
+    }
 
+    #[tokio::test]
+    async fn it_should_pass_for_empty_bodies_without_content_type() {
+        let app = Router::new()
+            .route("/with", get(route_get_with_content_type))
+            .route("/without", get(route_get_without_content_type))
+            .route("/empty", get(route_get_empty));
         let server = TestServer::new(app).unwrap();
 
-        let bytes = server.get(&"/json").await.into_bytes();
-        let text = String::from_utf8_lossy(&bytes);
+        server.get("/empty").await.assert_has_content_type();
+    }
 
-        assert_eq!(text, r#"{"message":"it works?"}"#);
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected header 'content-type' to be present for a body of 6 bytes, it is missing, for request GET /without"
+    )]
+    async fn it_should_panic_if_content_type_is_forgotten() {
+        let app = Router::new()
+            .route("/with", get(route_get_with_content_type))
+            .route("/without", get(route_get_without_content_type))
+            .route("/empty", get(route_get_empty));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/without").await.assert_has_content_type();
     }
 }
 
 #[cfg(test)]
-mod test_json {
+mod test_assert_cache_control {
     use crate::TestServer;
+
+    use ::axum::response::AppendHeaders;
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::axum::Json;
-    use ::serde::Deserialize;
-    use ::serde::Serialize;
+    use ::http::header::CACHE_CONTROL;
+    use ::http::HeaderName;
 
-    #[derive(Serialize, Deserialize, PartialEq, Debug)]
-    struct ExampleResponse {
-        name: String,
-        age: u32,
+    async fn route_get_private() -> ([(::http::HeaderName, &'static str); 1], &'static str) {
+        ([(CACHE_CONTROL, "private, max-age=60")], "hello!")
     }
 
-    async fn route_get_json() -> Json<ExampleResponse> {
-        Json(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+    async fn route_get_no_store() -> ([(::http::HeaderName, &'static str); 1], &'static str) {
+        ([(CACHE_CONTROL, "no-store")], "hello!")
+    }
+
+    async fn route_get_no_header() -> &'static str {
+        "hello!"
+    }
+
+    async fn route_get_split_headers(
+    ) -> (AppendHeaders<[(HeaderName, &'static str); 2]>, &'static str) {
+        (
+            AppendHeaders([
+                (CACHE_CONTROL, "private"),
+                (CACHE_CONTROL, " max-age=\"060\" "),
+            ]),
+            "hello!",
+        )
     }
 
     #[tokio::test]
-    async fn it_should_deserialize_into_json() {
-        let app = Router::new().route(&"/json", get(route_get_json));
+    async fn it_should_pass_for_no_store() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/no_store")
+            .await
+            .assert_cache_control_no_store();
+    }
 
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected 'cache-control' to contain 'no-store', received 'private, max-age=60', for request GET /private"
+    )]
+    async fn it_should_panic_if_no_store_is_missing() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
         let server = TestServer::new(app).unwrap();
 
-        let response = server.get(&"/json").await.json::<ExampleResponse>();
+        server.get("/private").await.assert_cache_control_no_store();
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_private() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/private").await.assert_cache_control_private();
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected 'cache-control' to contain 'private', received 'no-store', for request GET /no_store"
+    )]
+    async fn it_should_panic_if_private_is_missing() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/no_store").await.assert_cache_control_private();
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_for_matching_max_age() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/private")
+            .await
+            .assert_cache_control_max_age(60);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected 'cache-control' to contain 'max-age=30', received 'private, max-age=60', for request GET /private"
+    )]
+    async fn it_should_panic_if_max_age_differs() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/private")
+            .await
+            .assert_cache_control_max_age(30);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected header 'cache-control' to be present, it is missing, for request GET /no_header"
+    )]
+    async fn it_should_panic_if_header_is_missing() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        server
+            .get("/no_header")
+            .await
+            .assert_cache_control_no_store();
+    }
+
+    #[tokio::test]
+    async fn it_should_read_directives_across_multiple_headers() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/split_headers").await;
+
+        response.assert_cache_control_private();
+        response.assert_cache_control_max_age(60);
+    }
+
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected 'cache-control' to contain 'max-age=30', received 'private, max-age=\"060\"', for request GET /split_headers"
+    )]
+    async fn it_should_panic_if_max_age_differs_across_multiple_headers() {
+        let app = Router::new()
+            .route("/private", get(route_get_private))
+            .route("/split_headers", get(route_get_split_headers))
+            .route("/no_store", get(route_get_no_store))
+            .route("/no_header", get(route_get_no_header));
+        let server = TestServer::new(app).unwrap();
 
-        assert_eq!(
-            response,
-            ExampleResponse {
-                name: "Joe".to_string(),
-                age: 20,
-            }
-        );
+        server
+            .get("/split_headers")
+            .await
+            .assert_cache_control_max_age(30);
     }
 }
 
-#[cfg(feature = "yaml")]
 #[cfg(test)]
-mod test_yaml {
+mod test_cookie_changes {
     use crate::TestServer;
+    use crate::TestServerConfig;
+
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::axum_yaml::Yaml;
-    use ::serde::Deserialize;
-    use ::serde::Serialize;
+    use ::axum_extra::extract::cookie::CookieJar;
+    use ::cookie::Cookie;
 
-    #[derive(Serialize, Deserialize, PartialEq, Debug)]
-    struct ExampleResponse {
-        name: String,
-        age: u32,
+    async fn route_get_login(cookies: CookieJar) -> (CookieJar, &'static str) {
+        let cookies = cookies
+            .add(Cookie::new("session", "abc"))
+            .add(Cookie::new("theme", "dark"));
+
+        (cookies, "logged in")
     }
 
-    async fn route_get_yaml() -> Yaml<ExampleResponse> {
-        Yaml(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+    async fn route_get_refresh(cookies: CookieJar) -> (CookieJar, &'static str) {
+        let cookies = cookies
+            .add(Cookie::new("session", "def"))
+            .add(Cookie::new("theme", "dark"))
+            .remove(Cookie::from("tracking"));
+
+        (cookies, "refreshed")
     }
 
     #[tokio::test]
-    async fn it_should_deserialize_into_yaml() {
-        let app = Router::new().route(&"/yaml", get(route_get_yaml));
+    async fn it_should_report_added_cookies() {
+        let app = Router::new()
+            .route("/login", get(route_get_login))
+            .route("/refresh", get(route_get_refresh));
+        let config = TestServerConfig::builder().save_cookies().build();
+        let server = TestServer::new_with_config(app, config).unwrap();
+
+        let changes = server.get("/login").await.cookie_changes();
+
+        let mut added_names: Vec<&str> = changes.added().iter().map(Cookie::name).collect();
+        added_names.sort();
+        assert_eq!(added_names, vec!["session", "theme"]);
+        assert!(changes.updated().is_empty());
+        assert!(changes.removed().is_empty());
+    }
 
-        let server = TestServer::new(app).unwrap();
+    #[tokio::test]
+    async fn it_should_report_updated_and_removed_cookies() {
+        let app = Router::new()
+            .route("/login", get(route_get_login))
+            .route("/refresh", get(route_get_refresh));
+        let config = TestServerConfig::builder().save_cookies().build();
+        let mut server = TestServer::new_with_config(app, config).unwrap();
+
+        server.get("/login").await;
+        server.add_cookie(Cookie::new("tracking", "123"));
+
+        let changes = server.get("/refresh").await.cookie_changes();
+
+        assert!(changes.added().is_empty());
+        assert_eq!(changes.updated().len(), 1);
+        assert_eq!(changes.updated()[0].name(), "session");
+        assert_eq!(changes.updated()[0].value(), "def");
+        assert_eq!(changes.removed().len(), 1);
+        assert_eq!(changes.removed()[0].name(), "tracking");
+    }
 
-        let response = server.get(&"/yaml").await.yaml::<ExampleResponse>();
+    #[tokio::test]
+    async fn it_should_be_empty_when_nothing_changes() {
+        let app = Router::new()
+            .route("/login", get(route_get_login))
+            .route("/refresh", get(route_get_refresh));
+        let config = TestServerConfig::builder().save_cookies().build();
+        let server = TestServer::new_with_config(app, config).unwrap();
 
-        assert_eq!(
-            response,
-            ExampleResponse {
-                name: "Joe".to_string(),
-                age: 20,
-            }
-        );
+        server.get("/login").await;
+
+        let changes = server.get("/login").await.cookie_changes();
+
+        assert!(changes.is_empty());
     }
 }
 
 #[cfg(test)]
-mod test_form {
+mod test_assert_no_cookies {
     use crate::TestServer;
+
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::axum::Form;
-    use ::serde::Deserialize;
-    use ::serde::Serialize;
+    use ::axum_extra::extract::cookie::Cookie as AxumCookie;
+    use ::axum_extra::extract::cookie::CookieJar as AxumCookieJar;
 
-    #[derive(Serialize, Deserialize, PartialEq, Debug)]
-    struct ExampleResponse {
-        name: String,
-        age: u32,
+    async fn route_get_with_cookie(cookies: AxumCookieJar) -> (AxumCookieJar, &'static str) {
+        (cookies.add(AxumCookie::new("session", "abc123")), "hello!")
     }
 
-    async fn route_get_form() -> Form<ExampleResponse> {
-        Form(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+    async fn route_get_without_cookie() -> &'static str {
+        "hello!"
     }
 
     #[tokio::test]
-    async fn it_should_deserialize_into_form() {
-        let app = Router::new().route(&"/form", get(route_get_form));
-
+    async fn it_should_pass_if_no_cookies_are_set() {
+        let app = Router::new()
+            .route("/with_cookie", get(route_get_with_cookie))
+            .route("/without_cookie", get(route_get_without_cookie));
         let server = TestServer::new(app).unwrap();
 
-        let response = server.get(&"/form").await.form::<ExampleResponse>();
+        server.get("/without_cookie").await.assert_no_cookies();
+    }
 
-        assert_eq!(
-            response,
-            ExampleResponse {
-                name: "Joe".to_string(),
-                age: 20,
-            }
-        );
+    #[tokio::test]
+    #[should_panic(
+        expected = "Expected no cookies to be set, found 'session=abc123', for request GET /with_cookie"
+    )]
+    async fn it_should_panic_if_a_cookie_is_set() {
+        let app = Router::new()
+            .route("/with_cookie", get(route_get_with_cookie))
+            .route("/without_cookie", get(route_get_without_cookie));
+        let server = TestServer::new(app).unwrap();
+
+        server.get("/with_cookie").await.assert_no_cookies();
     }
 }
 
 #[cfg(test)]
-mod test_assert_json {
+mod test_trailers {
     use crate::TestServer;
+    use crate::TestServerConfig;
+    use crate::Transport;
 
+    use ::axum::body::Body;
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::axum::Form;
-    use ::axum::Json;
-    use ::serde::Deserialize;
-    use ::serde::Serialize;
-
-    #[derive(Serialize, Deserialize, PartialEq, Debug)]
-    struct ExampleResponse {
-        name: String,
-        age: u32,
+    use ::bytes::Bytes;
+    use ::futures_util::stream;
+    use ::http::header::TE;
+    use ::http::header::TRAILER;
+    use ::http::HeaderMap;
+    use ::http::HeaderName;
+    use ::http::HeaderValue;
+    use ::http_body_util::BodyExt;
+    use ::http_body_util::StreamBody;
+    use ::hyper::body::Frame;
+    use ::std::convert::Infallible;
+
+    async fn route_get_with_trailers() -> Body {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", HeaderValue::from_static("0"));
+
+        let body = String::from("hello!")
+            .with_trailers(async move { Some(Ok::<_, Infallible>(trailers)) });
+
+        Body::new(body)
     }
 
-    async fn route_get_form() -> Form<ExampleResponse> {
-        Form(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
-    }
+    /// Over HTTP/1.1 trailers need a chunked body, so this avoids a known length,
+    /// and the trailer must be declared in the `Trailer` header.
+    async fn route_get_with_chunked_trailers() -> ([(HeaderName, &'static str); 1], Body) {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", HeaderValue::from_static("0"));
 
-    async fn route_get_json() -> Json<ExampleResponse> {
-        Json(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+        let frames = [
+            Ok::<_, Infallible>(Frame::data(Bytes::from_static(b"hello!"))),
+            Ok(Frame::trailers(trailers)),
+        ];
+
+        let body = Body::new(StreamBody::new(stream::iter(frames)));
+        ([(TRAILER, "grpc-status")], body)
     }
 
     #[tokio::test]
-    async fn it_should_match_json_returned() {
-        let app = Router::new().route(&"/json", get(route_get_json));
+    async fn it_should_return_none_without_trailers() {
+        let app = Router::new().route("/text", get(|| async { "hello!" }));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/text").await;
+
+        assert!(response.trailers().is_none());
+    }
 
+    #[tokio::test]
+    async fn it_should_return_trailers_sent() {
+        let app = Router::new().route("/trailers", get(route_get_with_trailers));
         let server = TestServer::new(app).unwrap();
 
-        server.get(&"/json").await.assert_json(&ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        });
+        let response = server.get("/trailers").await;
+        response.assert_text("hello!");
+        response.assert_trailer("grpc-status", "0");
+
+        let trailers = response.trailers().unwrap();
+        assert_eq!(trailers.get("grpc-status").unwrap(), "0");
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_response_is_different() {
-        let app = Router::new().route(&"/json", get(route_get_json));
+    async fn it_should_return_trailers_sent_over_http() {
+        let app = Router::new().route("/trailers", get(route_get_with_chunked_trailers));
+        let config = TestServerConfig {
+            transport: Some(Transport::HttpRandomPort),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(app, config).unwrap();
+
+        // Over HTTP/1.1, trailers are only sent to clients which ask for them.
+        let response = server
+            .get("/trailers")
+            .add_header(TE, HeaderValue::from_static("trailers"))
+            .await;
+        response.assert_text("hello!");
+        response.assert_trailer("grpc-status", "0");
+    }
 
+    #[tokio::test]
+    #[should_panic(expected = "Expected trailer 'grpc-status' to be \"1\"")]
+    async fn it_should_panic_if_trailer_value_differs() {
+        let app = Router::new().route("/trailers", get(route_get_with_trailers));
         let server = TestServer::new(app).unwrap();
 
-        server.get(&"/json").await.assert_json(&ExampleResponse {
-            name: "Julia".to_string(),
-            age: 25,
-        });
+        server
+            .get("/trailers")
+            .await
+            .assert_trailer("grpc-status", "1");
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_response_is_form() {
-        let app = Router::new().route(&"/form", get(route_get_form));
-
+    #[should_panic(expected = "Cannot find trailer grpc-message")]
+    async fn it_should_panic_if_trailer_is_missing() {
+        let app = Router::new().route("/trailers", get(route_get_with_trailers));
         let server = TestServer::new(app).unwrap();
 
-        server.get(&"/form").await.assert_json(&ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        });
+        server
+            .get("/trailers")
+            .await
+            .assert_trailer("grpc-message", "ok");
     }
 }
 
-#[cfg(feature = "yaml")]
 #[cfg(test)]
-mod test_assert_yaml {
+mod test_cookies_jar_merged_with_request {
     use crate::TestServer;
+    use crate::TestServerConfig;
 
     use ::axum::routing::get;
-    use ::axum::routing::Router;
-    use ::axum::Form;
-    use ::axum_yaml::Yaml;
-    use ::serde::Deserialize;
-    use ::serde::Serialize;
-
-    #[derive(Serialize, Deserialize, PartialEq, Debug)]
-    struct ExampleResponse {
-        name: String,
-        age: u32,
-    }
-
-    async fn route_get_form() -> Form<ExampleResponse> {
-        Form(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
-    }
-
-    async fn route_get_yaml() -> Yaml<ExampleResponse> {
-        Yaml(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+    use ::axum::routing::put;
+    use ::axum::Router;
+    use ::axum_extra::extract::cookie::Cookie as AxumCookie;
+    use ::axum_extra::extract::cookie::CookieJar;
+    use ::cookie::Cookie;
+
+    async fn put_cookies(cookies: CookieJar) -> (CookieJar, &'static str) {
+        let cookies = cookies
+            .add(AxumCookie::new("first-cookie", "first"))
+            .add(AxumCookie::new("second-cookie", "second"));
+
+        (cookies, "done")
     }
 
-    #[tokio::test]
-    async fn it_should_match_yaml_returned() {
-        let app = Router::new().route(&"/yaml", get(route_get_yaml));
-
-        let server = TestServer::new(app).unwrap();
+    async fn get_remove_cookie(cookies: CookieJar) -> (CookieJar, &'static str) {
+        let cookies = cookies.remove(AxumCookie::from("first-cookie"));
 
-        server.get(&"/yaml").await.assert_yaml(&ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        });
+        (cookies, "done")
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_response_is_different() {
-        let app = Router::new().route(&"/yaml", get(route_get_yaml));
-
-        let server = TestServer::new(app).unwrap();
-
-        server.get(&"/yaml").await.assert_yaml(&ExampleResponse {
-            name: "Julia".to_string(),
-            age: 25,
-        });
+    async fn it_should_include_request_and_response_cookies() {
+        let app = Router::new().route("/cookies", put(put_cookies));
+        let server = TestServer::new(app).expect("Should create test server");
+
+        let response = server
+            .put("/cookies")
+            .add_cookie(Cookie::new("request-cookie", "request"))
+            .await;
+        let cookies = response.cookies_jar_merged_with_request();
+
+        assert_eq!(cookies.get("request-cookie").unwrap().value(), "request");
+        assert_eq!(cookies.get("first-cookie").unwrap().value(), "first");
+        assert_eq!(cookies.get("second-cookie").unwrap().value(), "second");
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_response_is_form() {
-        let app = Router::new().route(&"/form", get(route_get_form));
-
-        let server = TestServer::new(app).unwrap();
-
-        server.get(&"/form").await.assert_yaml(&ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        });
+    async fn it_should_remove_cookies_deleted_by_response() {
+        let app = Router::new()
+            .route("/cookies", put(put_cookies))
+            .route("/cookies/remove", get(get_remove_cookie));
+        let config = TestServerConfig::builder().save_cookies().build();
+        let server = TestServer::new_with_config(app, config).expect("Should create test server");
+
+        server.put("/cookies").await;
+        let response = server.get("/cookies/remove").await;
+        let cookies = response.cookies_jar_merged_with_request();
+
+        assert!(cookies.get("first-cookie").is_none());
+        assert_eq!(cookies.get("second-cookie").unwrap().value(), "second");
     }
 }
 
 #[cfg(test)]
-mod test_assert_form {
-    use crate::TestServer;
-
+mod test_check_status {
     use ::axum::routing::get;
     use ::axum::routing::Router;
-    use ::axum::Form;
-    use ::axum::Json;
-    use ::serde::Deserialize;
-    use ::serde::Serialize;
+    use ::http::StatusCode;
 
-    #[derive(Serialize, Deserialize, PartialEq, Debug)]
-    struct ExampleResponse {
-        name: String,
-        age: u32,
-    }
+    use crate::TestServer;
 
-    async fn route_get_form() -> Form<ExampleResponse> {
-        Form(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+    async fn route_get_ok() -> StatusCode {
+        StatusCode::OK
     }
 
-    async fn route_get_json() -> Json<ExampleResponse> {
-        Json(ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        })
+    async fn route_get_not_found() -> StatusCode {
+        StatusCode::NOT_FOUND
     }
 
     #[tokio::test]
-    async fn it_should_match_form_returned() {
-        let app = Router::new().route(&"/form", get(route_get_form));
-
+    async fn it_should_return_ok_when_status_matches() {
+        let app = Router::new()
+            .route("/ok", get(route_get_ok))
+            .route("/not_found", get(route_get_not_found));
         let server = TestServer::new(app).unwrap();
 
-        server.get(&"/form").await.assert_form(&ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        });
+        let response = server.get("/ok").await;
+
+        assert!(response.check_status_ok().is_ok());
+        assert!(response.check_status_success().is_ok());
+        assert!(response.check_not_status(StatusCode::NOT_FOUND).is_ok());
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_response_is_different() {
-        let app = Router::new().route(&"/form", get(route_get_form));
-
+    async fn it_should_return_err_without_panicking_when_status_differs() {
+        let app = Router::new()
+            .route("/ok", get(route_get_ok))
+            .route("/not_found", get(route_get_not_found));
         let server = TestServer::new(app).unwrap();
 
-        server.get(&"/form").await.assert_form(&ExampleResponse {
-            name: "Julia".to_string(),
-            age: 25,
-        });
+        let response = server.get("/not_found").await;
+
+        let error = response.check_status_ok().unwrap_err();
+        assert_eq!(
+            error.message(),
+            "Expected status code 200 (OK), got 404 (Not Found), for request GET /not_found"
+        );
+
+        assert!(response.check_status_success().is_err());
+        assert!(response.check_status_not_ok().is_ok());
+        assert!(response.check_status_failure().is_ok());
     }
 
     #[tokio::test]
-    #[should_panic]
-    async fn it_should_panic_if_response_is_json() {
-        let app = Router::new().route(&"/json", get(route_get_json));
-
+    #[should_panic(
+        expected = "Expected status code 200 (OK), got 404 (Not Found), for request GET /not_found"
+    )]
+    async fn it_should_panic_with_same_message_when_asserting() {
+        let app = Router::new()
+            .route("/ok", get(route_get_ok))
+            .route("/not_found", get(route_get_not_found));
         let server = TestServer::new(app).unwrap();
 
-        server.get(&"/json").await.assert_form(&ExampleResponse {
-            name: "Joe".to_string(),
-            age: 20,
-        });
+        server.get("/not_found").await.assert_status_ok();
     }
 }
 
 #[cfg(test)]
-mod test_text {
-    use crate::TestServer;
+mod test_check_json {
     use ::axum::routing::get;
     use ::axum::routing::Router;
+    use ::axum::Json;
+    use ::serde_json::json;
+    use ::serde_json::Value;
+
+    use crate::TestServer;
+
+    async fn route_get_json() -> Json<Value> {
+        Json(json!({ "name": "Joe" }))
+    }
 
     #[tokio::test]
-    async fn it_should_deserialize_into_text() {
-        async fn route_get_text() -> String {
-            "hello!".to_string()
-        }
+    async fn it_should_return_ok_when_json_matches() {
+        let app = Router::new().route("/json", get(route_get_json));
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/json").await;
 
-        let app = Router::new().route(&"/text", get(route_get_text));
+        assert!(response.check_json(&json!({ "name": "Joe" })).is_ok());
+        assert!(response.check_text(r#"{"name":"Joe"}"#).is_ok());
+    }
 
+    #[tokio::test]
+    async fn it_should_return_err_without_panicking_when_json_differs() {
+        let app = Router::new().route("/json", get(route_get_json));
         let server = TestServer::new(app).unwrap();
 
-        let response = server.get(&"/text").await.text();
+        let response = server.get("/json").await;
 
-        assert_eq!(response, "hello!");
+        let error = response
+            .check_json(&json!({ "name": "Julia" }))
+            .unwrap_err();
+        assert!(error.message().starts_with("Expected Json"));
+        assert!(error.message().ends_with("for request GET /json"));
+
+        assert!(response.check_text("Julia").is_err());
     }
 }
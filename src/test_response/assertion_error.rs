@@ -0,0 +1,33 @@
+use ::std::error::Error;
+use ::std::fmt;
+
+///
+/// The error returned by the `check_*` functions on [`TestResponse`](crate::TestResponse),
+/// when the response does not match what was expected.
+///
+/// The message is the same text the equivalent `assert_*` function would panic with.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertionError {
+    message: String,
+}
+
+impl AssertionError {
+    pub(crate) fn new(message: String) -> Self {
+        Self { message }
+    }
+
+    /// Returns the description of why the assertion failed.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for AssertionError {}
@@ -0,0 +1,97 @@
+use ::anyhow::anyhow;
+use ::anyhow::Error as AnyhowError;
+use ::std::str::FromStr;
+
+///
+/// A parsed `Content-Range` header, from a `206 Partial Content` response.
+///
+/// This is returned by [`TestResponse::content_range()`](crate::TestResponse::content_range()).
+/// Only byte ranges are supported, such as `bytes 0-499/1234`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    start: u64,
+    end: u64,
+    complete_length: Option<u64>,
+}
+
+impl ContentRange {
+    /// The position of the first byte in the range.
+    #[must_use]
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// The position of the last byte in the range, which is inclusive.
+    #[must_use]
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// The length of the whole resource, or `None` when it is unknown (sent as `*`).
+    #[must_use]
+    pub fn complete_length(&self) -> Option<u64> {
+        self.complete_length
+    }
+}
+
+impl FromStr for ContentRange {
+    type Err = AnyhowError;
+
+    fn from_str(content_range: &str) -> Result<Self, Self::Err> {
+        let invalid = || anyhow!("Invalid `Content-Range` header '{content_range}'");
+
+        let range_and_length = content_range
+            .trim()
+            .strip_prefix("bytes ")
+            .ok_or_else(invalid)?;
+        let (range, complete_length) = range_and_length.split_once('/').ok_or_else(invalid)?;
+        let (start, end) = range.split_once('-').ok_or_else(invalid)?;
+
+        let start = start.parse::<u64>().map_err(|_| invalid())?;
+        let end = end.parse::<u64>().map_err(|_| invalid())?;
+        let complete_length = match complete_length {
+            "*" => None,
+            complete_length => Some(complete_length.parse::<u64>().map_err(|_| invalid())?),
+        };
+
+        if end < start {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            start,
+            end,
+            complete_length,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_from_str {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_byte_range_with_length() {
+        let content_range = "bytes 0-499/1234".parse::<ContentRange>().unwrap();
+
+        assert_eq!(content_range.start(), 0);
+        assert_eq!(content_range.end(), 499);
+        assert_eq!(content_range.complete_length(), Some(1234));
+    }
+
+    #[test]
+    fn it_should_parse_byte_range_with_unknown_length() {
+        let content_range = "bytes 10-19/*".parse::<ContentRange>().unwrap();
+
+        assert_eq!(content_range.complete_length(), None);
+    }
+
+    #[test]
+    fn it_should_error_for_invalid_ranges() {
+        assert!("bytes */1234".parse::<ContentRange>().is_err());
+        assert!("items 0-10/20".parse::<ContentRange>().is_err());
+        assert!("bytes 10-5/20".parse::<ContentRange>().is_err());
+        assert!("bytes 0-abc/20".parse::<ContentRange>().is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use ::cookie::Cookie;
+
+///
+/// The cookies changed by a response, compared to the cookies sent with it's request.
+///
+/// This is returned by [`TestResponse::cookie_changes()`](crate::TestResponse::cookie_changes()).
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CookieChanges {
+    added: Vec<Cookie<'static>>,
+    updated: Vec<Cookie<'static>>,
+    removed: Vec<Cookie<'static>>,
+}
+
+impl CookieChanges {
+    pub(crate) fn push_added(&mut self, cookie: Cookie<'static>) {
+        self.added.push(cookie);
+    }
+
+    pub(crate) fn push_updated(&mut self, cookie: Cookie<'static>) {
+        self.updated.push(cookie);
+    }
+
+    pub(crate) fn push_removed(&mut self, cookie: Cookie<'static>) {
+        self.removed.push(cookie);
+    }
+
+    /// Cookies set by the response, which were not sent with the request.
+    #[must_use]
+    pub fn added(&self) -> &[Cookie<'static>] {
+        &self.added
+    }
+
+    /// Cookies set by the response, which replace a cookie sent with the request
+    /// with a different value.
+    #[must_use]
+    pub fn updated(&self) -> &[Cookie<'static>] {
+        &self.updated
+    }
+
+    /// Cookies sent with the request, which the response asked to be removed.
+    #[must_use]
+    pub fn removed(&self) -> &[Cookie<'static>] {
+        &self.removed
+    }
+
+    /// Returns true if the response did not change any cookies.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
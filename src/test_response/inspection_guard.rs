@@ -0,0 +1,51 @@
+use ::std::sync::atomic::AtomicBool;
+use ::std::sync::atomic::Ordering;
+use ::std::sync::Arc;
+use ::std::thread;
+
+use crate::internals::RequestPathFormatter;
+
+/// Tracks if a response has been inspected, such as by an assertion or reading the body.
+///
+/// When required, dropping the last copy of an uninspected response will panic.
+/// This is shared between clones of a response, so inspecting any clone counts.
+#[derive(Debug, Clone)]
+pub(crate) struct InspectionGuard {
+    is_required: bool,
+    is_inspected: Arc<AtomicBool>,
+    request_format: RequestPathFormatter,
+}
+
+impl InspectionGuard {
+    pub(crate) fn new(request_format: RequestPathFormatter) -> Self {
+        Self {
+            is_required: false,
+            is_inspected: Arc::new(AtomicBool::new(false)),
+            request_format,
+        }
+    }
+
+    /// Requires the response to be inspected before it is dropped,
+    /// ignoring any inspections made up until now.
+    pub(crate) fn require_inspection(&mut self) {
+        self.is_required = true;
+        self.is_inspected.store(false, Ordering::Relaxed);
+    }
+
+    pub(crate) fn mark_inspected(&self) {
+        self.is_inspected.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for InspectionGuard {
+    fn drop(&mut self) {
+        let is_last_copy = Arc::strong_count(&self.is_inspected) == 1;
+        let is_uninspected = !self.is_inspected.load(Ordering::Relaxed);
+
+        // Panicking whilst already panicking would abort the test run.
+        if self.is_required && is_last_copy && is_uninspected && !thread::panicking() {
+            let request_format = &self.request_format;
+            panic!("Response was dropped without any assertions or reads of it, for request {request_format}");
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use ::std::time::Duration;
+
+///
+/// Timing statistics for all of the requests made from a [`TestServer`](crate::TestServer).
+///
+/// The duration of a request covers sending it, and receiving the full response body.
+///
+/// This is returned by [`TestServer::request_stats()`](crate::TestServer::request_stats()).
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RequestStats {
+    count: u32,
+    total: Duration,
+    min: Option<Duration>,
+    max: Option<Duration>,
+}
+
+impl RequestStats {
+    pub(crate) fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+
+    /// The number of requests which have been made.
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The duration of the fastest request made,
+    /// or `None` if no requests have been made.
+    #[must_use]
+    pub fn min(&self) -> Option<Duration> {
+        self.min
+    }
+
+    /// The duration of the slowest request made,
+    /// or `None` if no requests have been made.
+    #[must_use]
+    pub fn max(&self) -> Option<Duration> {
+        self.max
+    }
+
+    /// The average duration of all requests made,
+    /// or `None` if no requests have been made.
+    #[must_use]
+    pub fn mean(&self) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(self.total / self.count)
+    }
+}
+
+#[cfg(test)]
+mod test_record {
+    use super::*;
+
+    #[test]
+    fn it_should_have_no_durations_when_empty() {
+        let stats = RequestStats::default();
+
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.mean(), None);
+    }
+
+    #[test]
+    fn it_should_track_min_max_and_mean() {
+        let mut stats = RequestStats::default();
+        stats.record(Duration::from_millis(30));
+        stats.record(Duration::from_millis(10));
+        stats.record(Duration::from_millis(20));
+
+        assert_eq!(stats.count(), 3);
+        assert_eq!(stats.min(), Some(Duration::from_millis(10)));
+        assert_eq!(stats.max(), Some(Duration::from_millis(30)));
+        assert_eq!(stats.mean(), Some(Duration::from_millis(20)));
+    }
+}
@@ -7,15 +7,19 @@ use ::http::HeaderValue;
 use ::serde::Serialize;
 use ::std::sync::Arc;
 use ::std::sync::Mutex;
+use ::std::time::Duration;
 
+use crate::internals::merge_cookie_into_jar;
 use crate::internals::with_this_mut;
 use crate::internals::QueryParamsStore;
+use crate::RequestStats;
 
 #[derive(Debug)]
 pub(crate) struct ServerSharedState {
     cookies: CookieJar,
     query_params: QueryParamsStore,
     headers: Vec<(HeaderName, HeaderValue)>,
+    request_stats: RequestStats,
 }
 
 impl ServerSharedState {
@@ -24,24 +28,37 @@ impl ServerSharedState {
             cookies: CookieJar::new(),
             query_params: QueryParamsStore::new(),
             headers: Vec::new(),
+            request_stats: RequestStats::default(),
         }
     }
 
+    pub(crate) fn add_default_cookie(&mut self, cookie: Cookie<'static>) {
+        self.cookies.add(cookie);
+    }
+
+    #[allow(clippy::needless_lifetimes)]
     pub(crate) fn cookies<'a>(&'a self) -> &'a CookieJar {
         &self.cookies
     }
 
+    #[allow(clippy::needless_lifetimes)]
     pub(crate) fn query_params<'a>(&'a self) -> &'a QueryParamsStore {
         &self.query_params
     }
 
+    #[allow(clippy::needless_lifetimes)]
     pub(crate) fn headers<'a>(&'a self) -> &'a Vec<(HeaderName, HeaderValue)> {
         &self.headers
     }
 
+    pub(crate) fn request_stats(&self) -> RequestStats {
+        self.request_stats
+    }
+
     /// Adds the given cookies.
     ///
     /// They will be stored over the top of the existing cookies.
+    #[allow(clippy::needless_borrows_for_generic_args)]
     pub(crate) fn add_cookies_by_header<'a, I>(
         this: &mut Arc<Mutex<Self>>,
         cookie_headers: I,
@@ -57,7 +74,7 @@ impl ServerSharedState {
                     .unwrap();
 
                 let cookie: Cookie<'static> = Cookie::parse(cookie_header_str)?.into_owned();
-                this.cookies.add(cookie);
+                merge_cookie_into_jar(&mut this.cookies, cookie);
             }
 
             Ok(()) as Result<()>
@@ -116,6 +133,7 @@ impl ServerSharedState {
         with_this_mut(this, "clear_headers", |this| this.headers.clear())
     }
 
+    #[allow(clippy::extra_unused_lifetimes)]
     pub(crate) fn add_header<'c>(
         this: &mut Arc<Mutex<Self>>,
         name: HeaderName,
@@ -123,4 +141,13 @@ impl ServerSharedState {
     ) -> Result<()> {
         with_this_mut(this, "add_header", |this| this.headers.push((name, value)))
     }
+
+    pub(crate) fn record_request_duration(
+        this: &mut Arc<Mutex<Self>>,
+        duration: Duration,
+    ) -> Result<()> {
+        with_this_mut(this, "record_request_duration", |this| {
+            this.request_stats.record(duration)
+        })
+    }
 }
@@ -1,3 +1,9 @@
+use ::cookie::Cookie;
+use ::http::Version;
+use ::std::net::SocketAddr;
+use ::std::time::Duration;
+
+use crate::HttpClientConfig;
 use crate::TestServerConfigBuilder;
 use crate::Transport;
 
@@ -88,6 +94,162 @@ pub struct TestServerConfig {
     ///
     /// This overrides the default 'best efforts' approach of requests.
     pub default_content_type: Option<String>,
+
+    /// Set the default `User-Agent` header for all requests created by the `TestServer`.
+    ///
+    /// This can be overridden on a per request basis using
+    /// [`TestRequest::user_agent()`](crate::TestRequest::user_agent()).
+    ///
+    /// **Defaults** to `None`, where no `User-Agent` header is sent.
+    pub default_user_agent: Option<String>,
+
+    /// Set the default `Accept` header for all requests created by the `TestServer`.
+    ///
+    /// This can be overridden on a per request basis using
+    /// [`TestRequest::accept()`](crate::TestRequest::accept()).
+    ///
+    /// **Defaults** to `None`, where no `Accept` header is sent.
+    pub default_accept: Option<String>,
+
+    /// Cookies to add to the `TestServer` when it is created.
+    /// These will be sent on every request, until they are cleared or replaced.
+    ///
+    /// This is the same as calling [`TestServer::add_cookie()`](crate::TestServer::add_cookie())
+    /// for each cookie, straight after creating the server.
+    ///
+    /// **Defaults** to no cookies.
+    pub default_cookies: Vec<Cookie<'static>>,
+
+    /// Route paths registered by name, as pairs of the name and the path,
+    /// for building request paths with [`TestServer::url_for()`](crate::TestServer::url_for()).
+    ///
+    /// Axum routers do not carry names, so these are registered alongside the router.
+    /// Paths use the same syntax as Axum routes, such as `/users/:id`.
+    ///
+    /// **Defaults** to no named routes.
+    pub named_routes: Vec<(String, String)>,
+
+    /// Set the maximum size, in bytes, of a request body which can be sent.
+    ///
+    /// When set, requests with a larger body will panic before being sent.
+    /// This is a guard against tests accidentally generating enormous payloads.
+    ///
+    /// Streamed bodies are checked as they are read, during the request.
+    /// If more is read than the limit allows, the body is cut short,
+    /// and the request panics once it has been sent.
+    ///
+    /// **Defaults** to `None`, where there is no limit.
+    pub max_request_body_size: Option<usize>,
+
+    /// Set the maximum size, in bytes, of a response body which will be read.
+    ///
+    /// When set, reading a larger response body will stop at the limit, and panic.
+    /// This is a guard against misbehaving handlers returning endless bodies,
+    /// and running out of memory.
+    /// This is enforced by the transports within `axum-test`,
+    /// and not by custom transports.
+    ///
+    /// **Defaults** to `None`, where there is no limit.
+    pub max_response_body_size: Option<usize>,
+
+    /// Customises the HTTP client used to send requests,
+    /// when running on a real port. See [`HttpClientConfig`](crate::HttpClientConfig).
+    ///
+    /// This is ignored when using the mock transport.
+    ///
+    /// **Defaults** to `None`, where the default `hyper_util` client is used.
+    pub http_client_config: Option<HttpClientConfig>,
+
+    /// How long to wait for in-flight requests to finish,
+    /// when the `TestServer` is shut down or dropped,
+    /// before the server is forcibly aborted.
+    /// See [`TestServer::shutdown()`](crate::TestServer::shutdown()).
+    ///
+    /// This is ignored when using the mock transport.
+    ///
+    /// **Defaults** to `None`, where the server is aborted straight away.
+    pub shutdown_timeout: Option<Duration>,
+
+    /// When a handler panics under the mock transport,
+    /// this will turn the panic into a response with a `500` status code.
+    /// The panic message is used as the body of the response.
+    ///
+    /// When off, the request will panic instead,
+    /// with a message naming the request and the handler's panic message.
+    ///
+    /// This has no effect when running on a real port.
+    ///
+    /// **Defaults** to false (being turned off).
+    pub handler_panics_as_500: bool,
+
+    /// When a request fails the expected state,
+    /// set by `expect_success_by_default` or [`TestRequest::expect_success()`](crate::TestRequest::expect_success()),
+    /// this will include the response body in the panic message.
+    ///
+    /// This can be turned off for individual requests using
+    /// [`TestRequest::no_body_in_errors()`](crate::TestRequest::no_body_in_errors()).
+    ///
+    /// **Defaults** to false (being turned off).
+    pub include_body_in_assertion_errors: bool,
+
+    /// When turned on, every response with a non-empty body
+    /// must have a `Content-Type` header, or the request will panic.
+    ///
+    /// This is the same as calling
+    /// [`TestResponse::assert_has_content_type()`](crate::TestResponse::assert_has_content_type())
+    /// on every response.
+    ///
+    /// **Defaults** to false (being turned off).
+    pub require_content_type: bool,
+
+    /// When turned on, sending a `GET` or `HEAD` request with a body will panic.
+    ///
+    /// Bodies on these requests are usually a mistake in the test,
+    /// however some APIs do accept `GET` bodies (such as search endpoints),
+    /// which is why this is opt-in.
+    ///
+    /// **Defaults** to false (being turned off).
+    pub warn_on_get_body: bool,
+
+    /// When turned on, dropping a `TestResponse` without inspecting it will panic.
+    ///
+    /// This catches tests which send a request and forget to assert on the result.
+    /// Any assertion, or reading of the status, headers, or body, counts as inspecting it.
+    ///
+    /// **Defaults** to false (being turned off).
+    pub panic_on_unconsumed_response: bool,
+
+    /// Set the address handlers will see when extracting
+    /// [`ConnectInfo<SocketAddr>`](axum::extract::ConnectInfo), when using mock transport.
+    /// This can be overridden on a per request basis using
+    /// [`TestRequest::mock_connect_info()`](crate::TestRequest::mock_connect_info()).
+    ///
+    /// When set this takes precedence over any
+    /// [`MockConnectInfo`](axum::extract::connect_info::MockConnectInfo) layer on the application.
+    /// When running on a real port, the address of the real connection is used instead.
+    ///
+    /// **Defaults** to `None`, where no `ConnectInfo` is provided.
+    pub mock_connect_info: Option<SocketAddr>,
+
+    /// Set the default HTTP version for all requests created by the `TestServer`.
+    ///
+    /// This can be overridden on a per request basis using
+    /// [`TestRequest::http_version()`](crate::TestRequest::http_version()).
+    /// Under the mock transport, handlers will see this version on the request.
+    /// When running on a real port, the version negotiated by the HTTP client is used instead.
+    ///
+    /// **Defaults** to `None`, where requests are sent as HTTP/1.1.
+    pub default_http_version: Option<Version>,
+
+    /// When set, every request is sent with a newly generated UUID
+    /// in the header named, for correlating test output with handler logs.
+    ///
+    /// An id is not generated if the header has already been set on the `TestServer`.
+    /// The id sent is available from
+    /// [`TestResponse::request_id()`](crate::TestResponse::request_id()).
+    ///
+    /// **Defaults** to `None`, where no request id is generated.
+    pub auto_request_id: Option<String>,
 }
 
 impl TestServerConfig {
@@ -107,6 +269,7 @@ impl TestServerConfig {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for TestServerConfig {
     fn default() -> Self {
         Self {
@@ -115,6 +278,22 @@ impl Default for TestServerConfig {
             expect_success_by_default: false,
             restrict_requests_with_http_schema: false,
             default_content_type: None,
+            default_user_agent: None,
+            default_accept: None,
+            default_cookies: Vec::new(),
+            named_routes: Vec::new(),
+            max_request_body_size: None,
+            max_response_body_size: None,
+            http_client_config: None,
+            shutdown_timeout: None,
+            handler_panics_as_500: false,
+            include_body_in_assertion_errors: false,
+            require_content_type: false,
+            warn_on_get_body: false,
+            panic_on_unconsumed_response: false,
+            mock_connect_info: None,
+            default_http_version: None,
+            auto_request_id: None,
         }
     }
 }
@@ -1,5 +1,10 @@
+use ::cookie::Cookie;
+use ::http::Version;
 use ::std::net::IpAddr;
+use ::std::net::SocketAddr;
+use ::std::time::Duration;
 
+use crate::HttpClientConfig;
 use crate::TestServerConfig;
 use crate::Transport;
 
@@ -75,6 +80,98 @@ impl TestServerConfigBuilder {
         self
     }
 
+    pub fn default_user_agent(mut self, user_agent: &str) -> Self {
+        self.config.default_user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    pub fn default_accept(mut self, accept: &str) -> Self {
+        self.config.default_accept = Some(accept.to_string());
+        self
+    }
+
+    /// Sets up defaults for testing a Json REST API.
+    ///
+    /// This sends and accepts `application/json` by default,
+    /// and expects requests to succeed unless told otherwise.
+    pub fn rest_json(self) -> Self {
+        self.default_content_type(mime::APPLICATION_JSON.essence_str())
+            .default_accept(mime::APPLICATION_JSON.essence_str())
+            .expect_success_by_default()
+    }
+
+    pub fn default_cookie(mut self, cookie: Cookie<'_>) -> Self {
+        self.config.default_cookies.push(cookie.into_owned());
+        self
+    }
+
+    pub fn named_route(mut self, name: &str, path: &str) -> Self {
+        self.config
+            .named_routes
+            .push((name.to_string(), path.to_string()));
+        self
+    }
+
+    pub fn max_request_body_size(mut self, max_request_body_size: usize) -> Self {
+        self.config.max_request_body_size = Some(max_request_body_size);
+        self
+    }
+
+    pub fn max_response_body_size(mut self, max_response_body_size: usize) -> Self {
+        self.config.max_response_body_size = Some(max_response_body_size);
+        self
+    }
+
+    pub fn http_client_config(mut self, http_client_config: HttpClientConfig) -> Self {
+        self.config.http_client_config = Some(http_client_config);
+        self
+    }
+
+    pub fn handler_panics_as_500(mut self) -> Self {
+        self.config.handler_panics_as_500 = true;
+        self
+    }
+
+    pub fn include_body_in_assertion_errors(mut self) -> Self {
+        self.config.include_body_in_assertion_errors = true;
+        self
+    }
+
+    pub fn require_content_type(mut self) -> Self {
+        self.config.require_content_type = true;
+        self
+    }
+
+    pub fn warn_on_get_body(mut self) -> Self {
+        self.config.warn_on_get_body = true;
+        self
+    }
+
+    pub fn panic_on_unconsumed_response(mut self) -> Self {
+        self.config.panic_on_unconsumed_response = true;
+        self
+    }
+
+    pub fn shutdown_timeout(mut self, shutdown_timeout: Duration) -> Self {
+        self.config.shutdown_timeout = Some(shutdown_timeout);
+        self
+    }
+
+    pub fn mock_connect_info(mut self, socket_addr: SocketAddr) -> Self {
+        self.config.mock_connect_info = Some(socket_addr);
+        self
+    }
+
+    pub fn default_http_version(mut self, version: Version) -> Self {
+        self.config.default_http_version = Some(version);
+        self
+    }
+
+    pub fn auto_request_id(mut self, header_name: &str) -> Self {
+        self.config.auto_request_id = Some(header_name.to_string());
+        self
+    }
+
     pub fn expect_success_by_default(mut self) -> Self {
         self.config.expect_success_by_default = true;
         self
@@ -90,6 +187,7 @@ impl TestServerConfigBuilder {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for TestServerConfigBuilder {
     fn default() -> Self {
         Self {
@@ -99,6 +197,7 @@ impl Default for TestServerConfigBuilder {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod test_build {
     use super::*;
     use ::std::net::Ipv4Addr;
@@ -163,6 +262,159 @@ mod test_build {
         assert_eq!(config.default_content_type, Some("text/csv".to_string()));
     }
 
+    #[test]
+    fn it_should_set_default_user_agent_when_set() {
+        let config = TestServerConfig::builder()
+            .default_user_agent("my-test-agent")
+            .build();
+
+        assert_eq!(config.default_user_agent, Some("my-test-agent".to_string()));
+    }
+
+    #[test]
+    fn it_should_set_default_accept_when_set() {
+        let config = TestServerConfig::builder()
+            .default_accept("text/csv")
+            .build();
+
+        assert_eq!(config.default_accept, Some("text/csv".to_string()));
+    }
+
+    #[test]
+    fn it_should_set_json_defaults_for_rest_json() {
+        let config = TestServerConfig::builder().rest_json().build();
+
+        assert_eq!(
+            config.default_content_type,
+            Some("application/json".to_string())
+        );
+        assert_eq!(config.default_accept, Some("application/json".to_string()));
+        assert!(config.expect_success_by_default);
+    }
+
+    #[test]
+    fn it_should_add_default_cookies_when_set() {
+        let config = TestServerConfig::builder()
+            .default_cookie(Cookie::new("first", "1"))
+            .default_cookie(Cookie::new("second", "2"))
+            .build();
+
+        assert_eq!(
+            config.default_cookies,
+            vec![Cookie::new("first", "1"), Cookie::new("second", "2")]
+        );
+    }
+
+    #[test]
+    fn it_should_add_named_routes_when_set() {
+        let config = TestServerConfig::builder()
+            .named_route("users", "/users")
+            .named_route("user", "/users/:id")
+            .build();
+
+        assert_eq!(
+            config.named_routes,
+            vec![
+                ("users".to_string(), "/users".to_string()),
+                ("user".to_string(), "/users/:id".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_set_max_request_body_size_when_set() {
+        let config = TestServerConfig::builder()
+            .max_request_body_size(1024)
+            .build();
+
+        assert_eq!(config.max_request_body_size, Some(1024));
+    }
+
+    #[test]
+    fn it_should_set_max_response_body_size_when_set() {
+        let config = TestServerConfig::builder()
+            .max_response_body_size(2048)
+            .build();
+
+        assert_eq!(config.max_response_body_size, Some(2048));
+    }
+
+    #[test]
+    fn it_should_set_handler_panics_as_500_when_set() {
+        let config = TestServerConfig::builder().handler_panics_as_500().build();
+
+        assert!(config.handler_panics_as_500);
+    }
+
+    #[test]
+    fn it_should_set_include_body_in_assertion_errors_when_set() {
+        let config = TestServerConfig::builder()
+            .include_body_in_assertion_errors()
+            .build();
+
+        assert!(config.include_body_in_assertion_errors);
+    }
+
+    #[test]
+    fn it_should_set_require_content_type_when_set() {
+        let config = TestServerConfig::builder().require_content_type().build();
+
+        assert!(config.require_content_type);
+    }
+
+    #[test]
+    fn it_should_set_shutdown_timeout_when_set() {
+        let config = TestServerConfig::builder()
+            .shutdown_timeout(Duration::from_secs(3))
+            .build();
+
+        assert_eq!(config.shutdown_timeout, Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn it_should_set_mock_connect_info_when_set() {
+        let socket_addr = SocketAddr::from(([10, 0, 0, 1], 4000));
+        let config = TestServerConfig::builder()
+            .mock_connect_info(socket_addr)
+            .build();
+
+        assert_eq!(config.mock_connect_info, Some(socket_addr));
+    }
+
+    #[test]
+    fn it_should_set_default_http_version_when_set() {
+        let config = TestServerConfig::builder()
+            .default_http_version(Version::HTTP_2)
+            .build();
+
+        assert_eq!(config.default_http_version, Some(Version::HTTP_2));
+    }
+
+    #[test]
+    fn it_should_set_auto_request_id_when_set() {
+        let config = TestServerConfig::builder()
+            .auto_request_id("x-request-id")
+            .build();
+
+        assert_eq!(config.auto_request_id, Some("x-request-id".to_string()));
+    }
+
+    #[test]
+    fn it_should_set_warn_on_get_body_when_set() {
+        let config = TestServerConfig::builder().warn_on_get_body().build();
+
+        assert!(config.warn_on_get_body);
+    }
+
+    #[test]
+    fn it_should_set_panic_on_unconsumed_response_when_set() {
+        let config = TestServerConfig::builder()
+            .panic_on_unconsumed_response()
+            .build();
+
+        assert!(config.panic_on_unconsumed_response);
+    }
+
     #[test]
     fn it_should_set_expect_success_by_default_when_set() {
         let config = TestServerConfig::builder()
@@ -15,6 +15,17 @@ pub enum Transport {
     /// will be made over the network stack.
     HttpRandomPort,
 
+    /// With this transport mode, a real web server will be spun up
+    /// running on the port given, on `127.0.0.1`.
+    ///
+    /// This is the equivalent of using `Transport::HttpIpPort`,
+    /// with only the `port` set.
+    ///
+    /// The port is bound with `SO_REUSEADDR`, so it can be reused straight away
+    /// after a previous server on it has finished. `SO_REUSEPORT` is not set,
+    /// as that would allow two running servers to share the same port.
+    HttpPort(u16),
+
     /// With this transport mode, a real web server will be spun up.
     /// Where you can pick which IP and Port to use for this to bind to.
     ///
@@ -33,6 +44,7 @@ pub enum Transport {
     },
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Transport {
     fn default() -> Self {
         Self::MockHttp
@@ -5,6 +5,7 @@ use ::axum::serve;
 use ::axum::Router;
 use ::tokio::net::TcpListener as TokioTcpListener;
 use ::tokio::spawn;
+use ::tokio::sync::oneshot::channel;
 use ::url::Url;
 
 use super::IntoTransportLayer;
@@ -18,6 +19,8 @@ impl IntoTransportLayer for IntoMakeService<Router> {
         self,
         builder: TransportLayerBuilder,
     ) -> Result<Box<dyn TransportLayer>> {
+        let http_client = builder.http_client();
+        let shutdown_timeout = builder.shutdown_timeout();
         let (socket_addr, tcp_listener, maybe_reserved_port) =
             builder.tcp_listener_with_reserved_port()?;
 
@@ -25,9 +28,13 @@ impl IntoTransportLayer for IntoMakeService<Router> {
         tcp_listener.set_nonblocking(true)?;
         let tokio_tcp_listener = TokioTcpListener::from_std(tcp_listener)?;
 
+        let (shutdown_sender, shutdown_receiver) = channel();
         let server_handle = spawn(async move {
             serve(tokio_tcp_listener, self)
-            .await
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_receiver.await;
+                })
+                .await
             .with_context(|| format!("Failed to create ::axum::Server for TestServer, with address '{maybe_local_address:?}'"))
             .expect("Expect server to start serving");
         });
@@ -37,8 +44,11 @@ impl IntoTransportLayer for IntoMakeService<Router> {
 
         Ok(Box::new(HttpTransportLayer::new(
             server_handle,
+            shutdown_sender,
+            shutdown_timeout,
             maybe_reserved_port,
             server_url,
+            http_client,
         )))
     }
 
@@ -49,6 +59,7 @@ impl IntoTransportLayer for IntoMakeService<Router> {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
 mod test_into_http_transport_layer_for_into_make_service {
     use ::axum::extract::State;
     use ::axum::routing::get;
@@ -106,6 +117,7 @@ mod test_into_http_transport_layer_for_into_make_service {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
 mod test_into_mock_transport_layer_for_into_make_service {
     use ::axum::extract::State;
     use ::axum::routing::get;
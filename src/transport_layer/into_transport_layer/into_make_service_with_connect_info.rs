@@ -6,6 +6,7 @@ use ::axum::serve;
 use ::axum::Router;
 use ::tokio::net::TcpListener as TokioTcpListener;
 use ::tokio::spawn;
+use ::tokio::sync::oneshot::channel;
 use ::url::Url;
 use axum::serve::IncomingStream;
 
@@ -22,13 +23,19 @@ where
         self,
         builder: TransportLayerBuilder,
     ) -> Result<Box<dyn TransportLayer>> {
+        let http_client = builder.http_client();
+        let shutdown_timeout = builder.shutdown_timeout();
         let (socket_addr, tcp_listener, maybe_reserved_port) =
             builder.tcp_listener_with_reserved_port()?;
         tcp_listener.set_nonblocking(true)?;
         let tokio_tcp_listener = TokioTcpListener::from_std(tcp_listener)?;
 
+        let (shutdown_sender, shutdown_receiver) = channel();
         let server_handle = spawn(async move {
             serve(tokio_tcp_listener, self)
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown_receiver.await;
+                })
                 .await
                 .with_context(|| "Failed to create ::axum::Server for TestServer")
                 .expect("Expect server to start serving");
@@ -39,8 +46,11 @@ where
 
         Ok(Box::new(HttpTransportLayer::new(
             server_handle,
+            shutdown_sender,
+            shutdown_timeout,
             maybe_reserved_port,
             server_url,
+            http_client,
         )))
     }
 
@@ -57,6 +67,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
 mod test_into_http_transport_layer_for_into_make_service_with_connect_info {
     use ::axum::routing::get;
     use ::axum::Router;
@@ -19,6 +19,7 @@ impl IntoTransportLayer for Router<()> {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
 mod test_into_http_transport_layer {
     use ::axum::extract::State;
     use ::axum::routing::get;
@@ -72,6 +73,7 @@ mod test_into_http_transport_layer {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
 mod test_into_mock_transport_layer_for_router {
     use ::axum::extract::State;
     use ::axum::routing::get;
@@ -1,8 +1,12 @@
 mod into_transport_layer;
 pub use self::into_transport_layer::*;
 
+#[allow(clippy::module_inception)]
 mod transport_layer;
 pub use self::transport_layer::*;
 
 mod transport_layer_builder;
 pub use self::transport_layer_builder::*;
+
+mod transport_layer_service;
+pub use self::transport_layer_service::*;
@@ -7,10 +7,20 @@ use ::http::Request;
 use ::std::fmt::Debug;
 use ::url::Url;
 
+/// Sends requests to the application being tested.
+///
+/// A transport is shared between a `TestServer`, its clones, and their requests,
+/// which may be sent at the same time from other tasks and threads.
+/// This is why it must be `Send + Sync`, and requests are sent using `&self`.
 #[async_trait]
-pub trait TransportLayer: Debug {
-    async fn send(&mut self, request: Request<Body>) -> Result<(Parts, Bytes)>;
+pub trait TransportLayer: Debug + Send + Sync {
+    async fn send(&self, request: Request<Body>) -> Result<(Parts, Bytes)>;
+
+    #[allow(clippy::needless_lifetimes)]
     fn url<'a>(&'a self) -> Option<&'a Url> {
         None
     }
+
+    /// Stops the server behind this transport, if it is running one.
+    async fn shutdown(&self) {}
 }
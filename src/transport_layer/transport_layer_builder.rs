@@ -1,20 +1,45 @@
 use ::anyhow::Context;
 use ::anyhow::Result;
+use ::axum::body::Body;
+use ::hyper_util::client::legacy::connect::HttpConnector;
+use ::hyper_util::client::legacy::Client;
 use ::reserve_port::ReservedPort;
 use ::std::net::IpAddr;
 use ::std::net::SocketAddr;
 use ::std::net::TcpListener;
+use ::std::time::Duration;
 
 use crate::internals::StartingTcpSetup;
+use crate::HttpClientConfig;
 
 pub struct TransportLayerBuilder {
     ip: Option<IpAddr>,
     port: Option<u16>,
+    http_client_config: Option<HttpClientConfig>,
+    shutdown_timeout: Option<Duration>,
 }
 
 impl TransportLayerBuilder {
-    pub(crate) fn new(ip: Option<IpAddr>, port: Option<u16>) -> Self {
-        Self { ip, port }
+    pub(crate) fn new(
+        ip: Option<IpAddr>,
+        port: Option<u16>,
+        http_client_config: Option<HttpClientConfig>,
+        shutdown_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            ip,
+            port,
+            http_client_config,
+            shutdown_timeout,
+        }
+    }
+
+    pub(crate) fn shutdown_timeout(&self) -> Option<Duration> {
+        self.shutdown_timeout
+    }
+
+    pub(crate) fn http_client(&self) -> Client<HttpConnector, Body> {
+        HttpClientConfig::build_client(self.http_client_config.as_ref())
     }
 
     pub(crate) fn tcp_listener_with_reserved_port(
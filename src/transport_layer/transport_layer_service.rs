@@ -0,0 +1,48 @@
+use ::anyhow::Error as AnyhowError;
+use ::axum::body::Body;
+use ::http::Request;
+use ::http::Response;
+use ::std::future::Future;
+use ::std::pin::Pin;
+use ::std::sync::Arc;
+use ::std::task::Context;
+use ::std::task::Poll;
+use ::tower::Service;
+
+use crate::transport_layer::TransportLayer;
+
+///
+/// This wraps a [`TransportLayer`] as a [`tower::Service`],
+/// allowing [`tower::Layer`]s to be applied on top of it.
+///
+/// See [`TestServer::with_layer()`](crate::TestServer::with_layer()).
+///
+#[derive(Debug, Clone)]
+pub struct TransportLayerService {
+    transport: Arc<Box<dyn TransportLayer>>,
+}
+
+impl TransportLayerService {
+    pub(crate) fn new(transport: Arc<Box<dyn TransportLayer>>) -> Self {
+        Self { transport }
+    }
+}
+
+impl Service<Request<Body>> for TransportLayerService {
+    type Response = Response<Body>;
+    type Error = AnyhowError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let transport = self.transport.clone();
+
+        Box::pin(async move {
+            let (parts, response_bytes) = transport.send(request).await?;
+            Ok(Response::from_parts(parts, Body::from(response_bytes)))
+        })
+    }
+}
@@ -4,5 +4,5 @@ set -e
 
 cargo check
 cargo test --example=example-todo
-cargo test  --features yaml,pretty-assertions "$@"
+cargo test  --features yaml,pretty-assertions,macros,regex "$@"
 cargo test "$@"
@@ -0,0 +1,49 @@
+#![cfg(feature = "macros")]
+
+use ::axum::routing::get;
+use ::axum::Router;
+use ::axum_test::TestServer;
+use ::axum_test::TestServerConfig;
+use ::http::HeaderMap;
+use ::http::HeaderName;
+use ::http::HeaderValue;
+
+fn app() -> Router {
+    Router::new()
+        .route("/ping", get(|| async { "pong!" }))
+        .route(
+            "/header",
+            get(|headers: HeaderMap| async move {
+                headers
+                    .get("x-name")
+                    .map(|value| value.to_str().unwrap().to_string())
+                    .unwrap_or_default()
+            }),
+        )
+}
+
+#[axum_test::test(app)]
+async fn it_should_inject_server_running_the_app(server: TestServer) {
+    server.get("/ping").await.assert_text("pong!");
+}
+
+#[axum_test::test(Router::new().route("/hello", get(|| async { "hello!" })))]
+async fn it_should_build_app_from_expression(server: TestServer) {
+    server.get("/hello").await.assert_text("hello!");
+}
+
+#[axum_test::test(app, config = TestServerConfig::builder().expect_success_by_default().build())]
+#[should_panic]
+async fn it_should_pass_config_through(server: TestServer) {
+    server.get("/missing").await;
+}
+
+#[axum_test::test(app)]
+async fn it_should_allow_mutable_server(mut server: TestServer) {
+    server.add_header(
+        HeaderName::from_static("x-name"),
+        HeaderValue::from_static("Joe"),
+    );
+
+    server.get("/header").await.assert_text("Joe");
+}